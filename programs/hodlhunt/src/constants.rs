@@ -3,6 +3,7 @@ pub mod ocean {
     pub const CALM_FEEDING_BPS: u16 = 500; // 5%
     pub const STORM_FEEDING_BPS: u16 = 1000; // 10%
     pub const INITIAL_STORM_PROBABILITY_BPS: u16 = 250; // 25%
+    pub const VALUE_PER_SHARE_PRECISION: u64 = 1_000_000_000;
 }
 
 pub mod fees {
@@ -11,8 +12,50 @@ pub mod fees {
     pub const FEED_COMMISSION_DIVISOR: u64 = 10; // 10%
     pub const FEE_SPLIT_DIVISOR: u64 = 2; // 50/50 
     pub const CREATION_FEE_DIVISOR: u64 = 20; // 5% from deposit
+    pub const RESURRECTION_FEE_DISCOUNT_BPS: u64 = 5_000; // 50% off creation fees
     pub const BASIS_POINTS_DIVISOR: u64 = 10_000;
     pub const EXIT_FEE_BPS: u64 = 500; // 5%
+    pub const TRANSFER_FEE_BPS: u64 = 250; // 2.5% of the offer price
+    pub const MARKET_ROYALTY_BPS: u64 = 500; // 5% of the sale price
+    pub const MERGE_FEE_BPS: u64 = 200; // 2% of the absorbed fish value
+    pub const REAPER_BOUNTY_BPS: u64 = 100; // 1% of a starved fish value
+    pub const REFERRAL_CUT_BPS: u64 = 2_000; // 20% of the admin fee goes to the referrer
+}
+
+pub mod loyalty {
+    use super::ocean::DAY_DURATION;
+
+    // Discount tiers as (threshold, discount in basis points); the highest tier reached in
+    // each table applies and the three discounts add up to `MAX_DISCOUNT_BPS`.
+    pub const AGE_TIERS: [(i64, u64); 3] = [
+        (30 * DAY_DURATION, 500),    // 30 days: 5% off
+        (90 * DAY_DURATION, 1_000),  // 90 days: 10% off
+        (180 * DAY_DURATION, 2_000), // 180 days: 20% off
+    ];
+    pub const STREAK_TIERS: [(u32, u64); 3] = [
+        (3, 500),    // 3 on-time feedings: 5% off
+        (10, 1_000), // 10: 10% off
+        (25, 1_500), // 25: 15% off
+    ];
+    pub const HUNT_TIERS: [(u64, u64); 3] = [
+        (5, 500),    // 5 hunts: 5% off
+        (20, 1_000), // 20: 10% off
+        (50, 1_500), // 50: 15% off
+    ];
+    pub const MAX_DISCOUNT_BPS: u64 = 4_000; // 40%
+}
+
+pub mod streaks {
+    use super::ocean::DAY_DURATION;
+
+    // A feeding extends the streak only inside this window before the fish turns hungry.
+    pub const WINDOW_SECONDS: i64 = 2 * DAY_DURATION;
+    // Milestones as (streak length, share of the feeding's pool fee credited to the fish).
+    pub const MILESTONES: [(u32, u64); 3] = [
+        (4, 2_500),   // ~1 month: 25% of the pool fee
+        (13, 5_000),  // ~1 quarter: 50%
+        (26, 10_000), // ~half a year: 100%
+    ];
 }
 
 pub mod marks {
@@ -20,3 +63,88 @@ pub mod marks {
     pub const HIGH_RATE_THRESHOLD_SECONDS: i64 = 30 * 60; // 30 minutes
     pub const EXCLUSIVITY_SECONDS: i64 = 20 * 60; // 20 minutes
 }
+
+pub mod shields {
+    use super::ocean::DAY_DURATION;
+
+    pub const DAILY_PRICE_BPS: u64 = 100; // 1% of fish value per shielded day
+    pub const STORM_PRICE_MULTIPLIER_BPS: u64 = 20_000; // 2x while the ocean is stormy
+    pub const MIN_DURATION_SECONDS: i64 = 60 * 60; // 1 hour
+    pub const MAX_DURATION_SECONDS: i64 = 3 * DAY_DURATION;
+    pub const COOLDOWN_SECONDS: i64 = 2 * DAY_DURATION; // after a shield lapses
+    pub const SEASON_SECONDS: i64 = 30 * DAY_DURATION;
+    pub const MAX_SEASON_SHIELD_SECONDS: i64 = 7 * DAY_DURATION;
+}
+
+pub mod seasons {
+    use super::ocean::DAY_DURATION;
+
+    pub const MAX_POT_FEE_BPS: u64 = 5_000; // at most half of each admin fee
+    pub const FINALIZE_WINDOW_SECONDS: i64 = 3 * DAY_DURATION;
+    pub const CLAIM_WINDOW_SECONDS: i64 = 30 * DAY_DURATION; // after finalization closes
+    pub const PRIZE_RANKS: usize = 3;
+    pub const PRIZE_SPLIT_BPS: [u64; PRIZE_RANKS] = [5_000, 3_000, 2_000];
+    pub const MAX_CANDIDATES: usize = 10;
+}
+
+pub mod achievements {
+    pub const MAX_ACHIEVEMENTS: usize = 16; // capacity of the bitset
+    pub const HUNT_MILESTONE: u64 = 10;
+    pub const VALUE_MILESTONE_LAMPORTS: u64 = 10_000_000_000; // 10 SOL
+}
+
+pub mod profiles {
+    pub const MAX_HANDLE_LEN: usize = 32;
+    pub const MAX_FISH: usize = 32; // living fish listed per profile
+}
+
+pub mod leaderboard {
+    pub const SIZE: usize = 10; // entries per board
+    pub const MAX_PRUNE_BATCH: usize = 2 * SIZE; // every fish both boards can list
+}
+
+pub mod batch {
+    pub const MAX_FEED_BATCH: usize = 16;
+}
+
+pub mod auto_feed {
+    pub const WINDOW_SECONDS: i64 = 6 * 60 * 60; // 6 hours before hunger
+    pub const KEEPER_TIP_LAMPORTS: u64 = 1_000_000; // 0.001 SOL
+}
+
+pub mod session {
+    pub const MAX_DURATION_SECONDS: i64 = 8 * 60 * 60; // 8 hours
+    pub const MAX_BUDGET_LAMPORTS: u64 = 1_000_000_000; // 1 SOL
+}
+
+pub mod hunting {
+    pub const DEFAULT_SLIPPAGE_BPS: u64 = 500; // 5%
+
+    // Size-ratio tiers as (minimum hunter/prey ratio, bite fee, escape chance) in basis
+    // points. The bite fee is split evenly between pool and admin; the hunter keeps the
+    // rest of the bite.
+    pub const MIN_SIZE_RATIO_BPS: u64 = 11_000; // hunter must be at least 1.1x the prey
+    pub const SIZE_TIERS: [(u64, u64, u64); 4] = [
+        (MIN_SIZE_RATIO_BPS, 3_000, 2_500), // close sizes: 30% fee, 25% escape
+        (15_000, 2_000, 1_500),             // 1.5x: 20% fee, 15% escape
+        (30_000, 1_500, 800),               // 3x: 15% fee, 8% escape
+        (100_000, 1_000, 300),              // 10x: 10% fee, 3% escape
+    ];
+    pub const STORM_ESCAPE_BONUS_BPS: u64 = 500; // +5% escape while stormy
+    pub const ESCAPE_DECAY_PER_DAY_BPS: u64 = 2_500; // escape fades 25% per day past hunger
+    pub const FAILED_HUNT_FEE_BPS: u64 = 200; // 2% of the hunter share on escape
+
+    // Reason bits reported by `check_hunt`
+    pub const CHECK_HUNTER_DEAD: u32 = 1 << 0;
+    pub const CHECK_PREY_DEAD: u32 = 1 << 1;
+    pub const CHECK_HUNTER_TRADE_LOCKED: u32 = 1 << 2;
+    pub const CHECK_SAME_FISH: u32 = 1 << 3;
+    pub const CHECK_SAME_OWNER: u32 = 1 << 4;
+    pub const CHECK_PREY_TOO_HEAVY: u32 = 1 << 5;
+    pub const CHECK_HUNTER_ON_COOLDOWN: u32 = 1 << 6;
+    pub const CHECK_PREY_PROTECTED: u32 = 1 << 7;
+    pub const CHECK_PREY_NOT_HUNGRY: u32 = 1 << 8;
+    pub const CHECK_MARK_EXCLUSIVITY: u32 = 1 << 9;
+    pub const CHECK_SLIPPAGE: u32 = 1 << 10;
+    pub const CHECK_SIZE_RATIO: u32 = 1 << 11;
+}
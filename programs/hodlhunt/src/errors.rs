@@ -19,8 +19,112 @@ pub enum ErrorCode {
     NotFishOwner,
     #[msg("Fish is already dead")]
     FishAlreadyDead,
+    #[msg("Fish has already been resurrected")]
+    FishAlreadyResurrected,
+    #[msg("Cannot merge a fish with itself")]
+    CannotMergeWithSelf,
+    #[msg("Split must leave at least the minimum deposit on both fish")]
+    InvalidSplit,
     #[msg("Cannot transfer fish to yourself")]
     CannotTransferToSelf,
+    #[msg("Account is not a legacy fish of this owner")]
+    InvalidLegacyFish,
+
+    // Transfer offers
+    #[msg("Fish is frozen by an open transfer offer or listing")]
+    FishTradeLocked,
+    #[msg("Transfer offer does not match this fish or owner")]
+    InvalidOffer,
+    #[msg("Transfer offer has expired")]
+    OfferExpired,
+    #[msg("Price is above the buyer's maximum")]
+    PriceAboveMax,
+    #[msg("Expiry must be in the future")]
+    InvalidExpiry,
+
+    // Delegation
+    #[msg("Delegation must grant at least one permission to another key")]
+    InvalidDelegation,
+    #[msg("Delegation has expired")]
+    DelegationExpired,
+    #[msg("Delegation does not grant this action")]
+    DelegationPermissionDenied,
+    #[msg("Delegation spending cap exceeded")]
+    DelegationCapExceeded,
+
+    // Session keys
+    #[msg("Session duration or budget is out of range")]
+    InvalidSession,
+    #[msg("Session has expired")]
+    SessionExpired,
+    #[msg("Session budget exceeded")]
+    SessionBudgetExceeded,
+
+    // Hunt intents
+    #[msg("Hunt intent is malformed or does not match this hunt")]
+    InvalidIntent,
+    #[msg("Hunt intent deadline has passed")]
+    IntentExpired,
+
+    // Shields
+    #[msg("Shield duration is out of range")]
+    InvalidShieldDuration,
+    #[msg("Fish is protected or its shield cooldown has not elapsed")]
+    ShieldOnCooldown,
+    #[msg("Shield time cap for this season reached")]
+    ShieldCapReached,
+
+    // Referrals
+    #[msg("Referrer cannot refer their own actions")]
+    InvalidReferral,
+    #[msg("No referral earnings to claim")]
+    NothingToClaim,
+
+    // Seasons
+    #[msg("Season parameters are invalid")]
+    InvalidSeason,
+    #[msg("Season has not ended yet")]
+    SeasonNotEnded,
+    #[msg("Season finalization window has closed")]
+    SeasonFinalizationClosed,
+    #[msg("Season standings are not final yet")]
+    SeasonNotFinalized,
+    #[msg("Season prize already claimed")]
+    PrizeAlreadyClaimed,
+    #[msg("Signer does not hold this season rank")]
+    NotSeasonWinner,
+    #[msg("Season overlaps the previous season of this ocean")]
+    SeasonOverlaps,
+    #[msg("Season prize claims have closed")]
+    SeasonClaimsClosed,
+    #[msg("Season prizes can still be claimed")]
+    SeasonClaimsOpen,
+
+    // Player profiles
+    #[msg("Profile handle is empty, too long or contains invalid characters")]
+    InvalidHandle,
+    #[msg("Profile already lists the maximum number of fish")]
+    ProfileFull,
+
+    // Marketplace
+    #[msg("Listing is no longer active")]
+    ListingInactive,
+    #[msg("Operation does not match the listing kind")]
+    WrongListingKind,
+    #[msg("Auction has not ended yet")]
+    AuctionNotEnded,
+    #[msg("Auction has already ended")]
+    AuctionEnded,
+    #[msg("Listing still has a bid that must be settled first")]
+    ListingHasBids,
+    #[msg("Bid is too low")]
+    BidTooLow,
+    #[msg("Bid does not belong to this listing or bidder")]
+    InvalidBid,
+    #[msg("Highest bid cannot be withdrawn while the auction is live")]
+    BidLocked,
+    #[msg("Listing is still live and must be cancelled by its seller")]
+    ListingStillLive,
 
     // Feeding / funds
     #[msg("Insufficient feeding amount")]
@@ -31,16 +135,32 @@ pub enum ErrorCode {
     InsufficientVaultBalance,
     #[msg("Math overflow/underflow")]
     MathOverflow,
+    #[msg("Batch is empty, too large or does not match its accounts")]
+    InvalidBatch,
+    #[msg("Fish is not close enough to hunger for auto-feed")]
+    AutoFeedNotDue,
+    #[msg("Required feeding exceeds the auto-feed cap")]
+    AutoFeedCapExceeded,
+    #[msg("Auto-feed escrow has insufficient balance")]
+    InsufficientEscrow,
 
     // Hunting validations
     #[msg("Prey is too heavy")]
     PreyTooHeavy,
+    #[msg("Hunter is not large enough relative to the prey")]
+    SizeRatioTooLow,
     #[msg("Hunter is on hunting cooldown")]
     HuntingOnCooldown,
     #[msg("Invalid prey")]
     InvalidPrey,
     #[msg("Slippage exceeded: prey weight changed more than 5%")]
     SlippageExceeded,
+    #[msg("Fish is not starving yet")]
+    NotStarving,
+    #[msg("Hunts cannot be invoked through another program")]
+    HuntViaCpi,
+    #[msg("Hunt must be the last instruction of its transaction")]
+    HuntNotLastInstruction,
 
     // Marks
     #[msg("Hunting mark limit exceeded (max 4 per ocean mode period)")]
@@ -59,6 +179,8 @@ pub enum ErrorCode {
     MarkExclusivityActive,
     #[msg("An active mark already exists for this prey")]
     MarkAlreadyActive,
+    #[msg("Fish carries an active hunting mark")]
+    FishMarked,
 
     // Exits / ocean
     #[msg("Cannot exit during storm")]
@@ -10,6 +10,190 @@ pub struct FishFed {
     pub pool_fee: u64,
     pub new_share: u64,
     pub new_value: u64,
+    pub fee_discount_bps: u64,
+    pub feeding_streak: u32,
+}
+
+#[event]
+pub struct StreakMilestone {
+    pub fish_id: u64,
+    pub owner: Pubkey,
+    pub feeding_streak: u32,
+    pub bonus_value: u64,
+    pub bonus_shares: u64,
+}
+
+#[event]
+pub struct AutoFeedFunded {
+    pub fish_id: u64,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub max_feed_amount: u64,
+    pub available: u64,
+}
+
+#[event]
+pub struct AutoFeedExecuted {
+    pub fish_id: u64,
+    pub keeper: Pubkey,
+    pub feeding_amount: u64,
+    pub total_cost: u64,
+    pub keeper_tip: u64,
+    pub available: u64,
+}
+
+#[event]
+pub struct AutoFeedWithdrawn {
+    pub fish_id: u64,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub closed: bool,
+}
+
+#[event]
+pub struct DelegationGranted {
+    pub owner: Pubkey,
+    pub delegate: Pubkey,
+    pub permissions: u8,
+    pub expires_at: i64,
+    pub spend_cap: u64,
+}
+
+#[event]
+pub struct DelegationRevoked {
+    pub owner: Pubkey,
+    pub delegate: Pubkey,
+    pub spent: u64,
+}
+
+#[event]
+pub struct SessionCreated {
+    pub owner: Pubkey,
+    pub session_key: Pubkey,
+    pub expires_at: i64,
+    pub budget: u64,
+}
+
+#[event]
+pub struct SessionRevoked {
+    pub owner: Pubkey,
+    pub session_key: Pubkey,
+    pub spent: u64,
+    pub swept: u64,
+}
+
+#[event]
+pub struct HuntIntentCreated {
+    pub hunter_id: u64,
+    pub owner: Pubkey,
+    pub prey_id: u64,
+    pub expected_prey_share: u64,
+    pub max_prey_share: u64,
+    pub max_slippage_bps: u64,
+    pub tip: u64,
+    pub deadline: i64,
+}
+
+#[event]
+pub struct HuntIntentExecuted {
+    pub hunter_id: u64,
+    pub prey_id: u64,
+    pub keeper: Pubkey,
+    pub tip: u64,
+    pub caught: bool,
+}
+
+#[event]
+pub struct HuntIntentCancelled {
+    pub hunter_id: u64,
+    pub owner: Pubkey,
+}
+
+#[event]
+pub struct ShieldPurchased {
+    pub fish_id: u64,
+    pub owner: Pubkey,
+    pub duration: i64,
+    pub cost: u64,
+    pub admin_fee: u64,
+    pub pool_fee: u64,
+    pub protection_ends_at: i64,
+    pub season_seconds_used: i64,
+}
+
+#[event]
+pub struct ReferrerRegistered {
+    pub referrer: Pubkey,
+}
+
+#[event]
+pub struct ReferralRewarded {
+    pub referrer: Pubkey,
+    pub payer: Pubkey,
+    pub admin_fee: u64,
+    pub amount: u64,
+    pub total_earned: u64,
+}
+
+#[event]
+pub struct ReferralClaimed {
+    pub referrer: Pubkey,
+    pub amount: u64,
+    pub total_claimed: u64,
+}
+
+#[event]
+pub struct SeasonOpened {
+    pub season_id: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub pot_fee_bps: u64,
+}
+
+#[event]
+pub struct SeasonStandingsUpdated {
+    pub season_id: u64,
+    pub winner_count: u8,
+    pub fish_ids: [u64; 3],
+    pub scores: [u64; 3],
+    pub prize_pot: u64,
+}
+
+#[event]
+pub struct SeasonPrizeClaimed {
+    pub season_id: u64,
+    pub rank: u8,
+    pub fish_id: u64,
+    pub owner: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct SeasonClosed {
+    pub season_id: u64,
+    pub claimed_mask: u8,
+    pub swept: u64,
+}
+
+#[event]
+pub struct AchievementUnlocked {
+    pub owner: Pubkey,
+    pub fish_id: u64,
+    pub achievement: u8,
+    pub unlocked_at: i64,
+}
+
+#[event]
+pub struct ProfileCreated {
+    pub owner: Pubkey,
+    pub handle: String,
+    pub referrer: Pubkey,
+}
+
+#[event]
+pub struct ProfileSynced {
+    pub owner: Pubkey,
+    pub fish_count: u8,
 }
 
 #[event]
@@ -31,6 +215,35 @@ pub struct FishHunted {
     pub bite_percent: u64,
     pub bite_fee_percent: u64,
     pub bite_fee: u64,
+    pub size_tier: u8,
+    pub size_ratio_bps: u64,
+}
+
+#[event]
+pub struct HuntFailed {
+    pub hunter_id: u64,
+    pub prey_id: u64,
+    pub hunter_owner: Pubkey,
+    pub prey_owner: Pubkey,
+    pub escape_chance_bps: u64,
+    pub roll_bps: u64,
+    pub fee_share: u64,
+    pub to_pool: u64,
+    pub to_admin: u64,
+    pub to_admin_value: u64,
+    pub hunter_new_share: u64,
+    pub can_hunt_after: i64,
+}
+
+#[event]
+pub struct FishStarved {
+    pub fish_id: u64,
+    pub owner: Pubkey,
+    pub reaper: Pubkey,
+    pub starved_share: u64,
+    pub redistributed_value: u64,
+    pub bounty: u64,
+    pub last_fed_at: i64,
 }
 
 #[event]
@@ -43,6 +256,7 @@ pub struct FishExited {
     pub pool_fee: u64,
     pub to_player: u64,
     pub new_balance: u64,
+    pub fee_discount_bps: u64,
 }
 
 #[event]
@@ -56,6 +270,31 @@ pub struct FishCreated {
     pub name: String,
 }
 
+#[event]
+pub struct FishMerged {
+    pub target_id: u64,
+    pub source_id: u64,
+    pub owner: Pubkey,
+    pub absorbed_share: u64,
+    pub new_share: u64,
+    pub merge_fee: u64,
+    pub admin_fee: u64,
+    pub pool_fee: u64,
+}
+
+#[event]
+pub struct FishSplit {
+    pub parent_id: u64,
+    pub child_id: u64,
+    pub owner: Pubkey,
+    pub name: String,
+    pub split_bps: u64,
+    pub child_share: u64,
+    pub parent_new_share: u64,
+    pub admin_fee: u64,
+    pub pool_fee: u64,
+}
+
 #[event]
 pub struct FishTransferred {
     pub fish_id: u64,
@@ -63,6 +302,89 @@ pub struct FishTransferred {
     pub to_owner: Pubkey,
 }
 
+#[event]
+pub struct TransferOfferCreated {
+    pub fish_id: u64,
+    pub seller: Pubkey,
+    pub recipient: Pubkey,
+    pub price: u64,
+    pub expires_at: i64,
+}
+
+#[event]
+pub struct TransferOfferAccepted {
+    pub fish_id: u64,
+    pub seller: Pubkey,
+    pub recipient: Pubkey,
+    pub price: u64,
+    pub protocol_fee: u64,
+    pub to_seller: u64,
+}
+
+#[event]
+pub struct TransferOfferCancelled {
+    pub fish_id: u64,
+    pub seller: Pubkey,
+    pub recipient: Pubkey,
+}
+
+#[event]
+pub struct FishListed {
+    pub fish_id: u64,
+    pub seller: Pubkey,
+    pub price: u64,
+    pub auction_ends_at: i64,
+}
+
+#[event]
+pub struct ListingCancelled {
+    pub fish_id: u64,
+    pub seller: Pubkey,
+    pub open_bids: u32,
+}
+
+#[event]
+pub struct ListingClosed {
+    pub fish_id: u64,
+    pub seller: Pubkey,
+    pub closed_by: Pubkey,
+    pub open_bids: u32,
+}
+
+#[event]
+pub struct BidPlaced {
+    pub fish_id: u64,
+    pub bidder: Pubkey,
+    pub amount: u64,
+    pub is_highest: bool,
+}
+
+#[event]
+pub struct BidRefunded {
+    pub fish_id: u64,
+    pub bidder: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct FishSold {
+    pub fish_id: u64,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub price: u64,
+    pub royalty: u64,
+    pub to_seller: u64,
+    pub via_auction: bool,
+}
+
+#[event]
+pub struct FishMigrated {
+    pub fish_id: u64,
+    pub owner: Pubkey,
+    pub legacy_address: Pubkey,
+    pub new_address: Pubkey,
+}
+
 #[event]
 pub struct FishResurrected {
     pub old_fish_id: u64,
@@ -73,6 +395,8 @@ pub struct FishResurrected {
     pub deposit: u64,
     pub admin_fee: u64,
     pub pool_fee: u64,
+    pub generation: u32,
+    pub fee_discount_bps: u64,
 }
 
 #[event]
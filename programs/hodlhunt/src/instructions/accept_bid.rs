@@ -0,0 +1,46 @@
+use super::common::{
+    close_listing_if_settled, complete_listing_sale, market_royalty, move_profile_fish,
+    refund_bids,
+};
+use crate::errors::ErrorCode;
+use crate::{instructions::AcceptBid, utils::*};
+use anchor_lang::prelude::*;
+
+/// Lets the seller of a fixed-price listing accept any open bid. The bid escrow pays
+/// the seller minus the admin royalty, the bidder takes over the fish, and other bids
+/// passed as `(bid, bidder)` pairs in remaining accounts are refunded.
+pub fn handle<'info>(ctx: Context<'_, '_, 'info, 'info, AcceptBid<'info>>) -> Result<()> {
+    let fish = &mut ctx.accounts.fish;
+    let listing = &mut ctx.accounts.listing;
+    let bid = &ctx.accounts.bid;
+    let seller = &ctx.accounts.seller;
+    let admin = &ctx.accounts.admin;
+
+    require!(listing.is_live(fish), ErrorCode::ListingInactive);
+    require!(!listing.is_auction(), ErrorCode::WrongListingKind);
+
+    let price = bid.amount;
+    let (royalty, to_seller) = market_royalty(price);
+    transfer_lamports(&bid.to_account_info(), &seller.to_account_info(), to_seller)?;
+    transfer_lamports(&bid.to_account_info(), admin, royalty)?;
+    listing.open_bids = listing.open_bids.saturating_sub(1);
+
+    complete_listing_sale(fish, listing, bid.bidder, price, royalty);
+    move_profile_fish(
+        ctx.accounts.seller_profile.as_mut(),
+        ctx.accounts.bidder_profile.as_mut(),
+        fish.id,
+    );
+    if let Some(leaderboard) = ctx.accounts.leaderboard.as_mut() {
+        leaderboard.record(fish, Clock::get()?.unix_timestamp);
+    }
+
+    let listing_key = listing.key();
+    refund_bids(
+        listing,
+        &listing_key,
+        Some(&bid.key()),
+        ctx.remaining_accounts,
+    )?;
+    close_listing_if_settled(listing, &seller.to_account_info())
+}
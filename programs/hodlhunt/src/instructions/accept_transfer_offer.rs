@@ -0,0 +1,79 @@
+use crate::constants::fees;
+use crate::errors::ErrorCode;
+use crate::instructions::common::move_profile_fish;
+use crate::{events::*, instructions::AcceptTransferOffer, utils::*, Fish};
+use anchor_lang::prelude::*;
+
+/// Completes a transfer offer on behalf of its recipient. The recipient pays the offer
+/// price, of which the protocol fee goes to the admin and the rest to the seller, and
+/// the fish is handed over in place and unfrozen. The call fails when the price is above
+/// `max_price`, guarding against the seller re-creating the offer at a higher price.
+pub fn handle(ctx: Context<AcceptTransferOffer>, max_price: u64) -> Result<()> {
+    let fish = &mut ctx.accounts.fish;
+    let offer = &ctx.accounts.offer;
+    let recipient = &ctx.accounts.recipient;
+    let seller = &ctx.accounts.seller;
+    let admin = &ctx.accounts.admin;
+    let system_program = &ctx.accounts.system_program;
+
+    let now = Clock::get()?.unix_timestamp;
+
+    fish.ensure_alive()?;
+    fish.ensure_owned_by(&offer.seller)?;
+    require!(
+        fish.trade_lock == Fish::TRADE_LOCK_OFFER,
+        ErrorCode::InvalidOffer
+    );
+    require!(!offer.is_expired(now), ErrorCode::OfferExpired);
+    require!(offer.fits_max_price(max_price), ErrorCode::PriceAboveMax);
+
+    let protocol_fee = offer
+        .price
+        .saturating_mul(fees::TRANSFER_FEE_BPS)
+        .saturating_div(fees::BASIS_POINTS_DIVISOR);
+    let to_seller = offer.price.saturating_sub(protocol_fee);
+    require!(
+        recipient.lamports() >= offer.price,
+        ErrorCode::InsufficientFunds
+    );
+
+    transfer_from_signer(
+        &recipient.to_account_info(),
+        seller,
+        &system_program.to_account_info(),
+        to_seller,
+    )?;
+    transfer_from_signer(
+        &recipient.to_account_info(),
+        admin,
+        &system_program.to_account_info(),
+        protocol_fee,
+    )?;
+
+    fish.owner = recipient.key();
+    fish.trade_lock = Fish::TRADE_LOCK_NONE;
+    move_profile_fish(
+        ctx.accounts.seller_profile.as_mut(),
+        ctx.accounts.recipient_profile.as_mut(),
+        fish.id,
+    );
+    if let Some(leaderboard) = ctx.accounts.leaderboard.as_mut() {
+        leaderboard.record(fish, now);
+    }
+
+    emit!(TransferOfferAccepted {
+        fish_id: fish.id,
+        seller: offer.seller,
+        recipient: recipient.key(),
+        price: offer.price,
+        protocol_fee,
+        to_seller,
+    });
+    emit!(FishTransferred {
+        fish_id: fish.id,
+        from_owner: offer.seller,
+        to_owner: recipient.key(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+use super::common::{
+    close_listing_if_settled, complete_listing_sale, market_royalty, move_profile_fish,
+    refund_bids,
+};
+use crate::errors::ErrorCode;
+use crate::{instructions::BuyListedFish, utils::*};
+use anchor_lang::prelude::*;
+
+/// Buys a fixed-price listing outright. The buyer pays the seller the listing price
+/// minus the admin royalty, takes over the fish, and any bids passed as `(bid, bidder)`
+/// pairs in remaining accounts are refunded. The call fails when the price is above
+/// `max_price`, guarding against the seller re-listing the fish at a higher price.
+pub fn handle<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BuyListedFish<'info>>,
+    max_price: u64,
+) -> Result<()> {
+    let fish = &mut ctx.accounts.fish;
+    let listing = &mut ctx.accounts.listing;
+    let buyer = &ctx.accounts.buyer;
+    let seller = &ctx.accounts.seller;
+    let admin = &ctx.accounts.admin;
+    let system_program = &ctx.accounts.system_program;
+
+    require!(listing.is_live(fish), ErrorCode::ListingInactive);
+    require!(!listing.is_auction(), ErrorCode::WrongListingKind);
+    require!(buyer.key() != listing.seller, ErrorCode::InvalidBid);
+    require!(listing.fits_max_price(max_price), ErrorCode::PriceAboveMax);
+
+    let price = listing.price;
+    let (royalty, to_seller) = market_royalty(price);
+    require!(buyer.lamports() >= price, ErrorCode::InsufficientFunds);
+
+    transfer_from_signer(
+        &buyer.to_account_info(),
+        seller,
+        &system_program.to_account_info(),
+        to_seller,
+    )?;
+    transfer_from_signer(
+        &buyer.to_account_info(),
+        admin,
+        &system_program.to_account_info(),
+        royalty,
+    )?;
+
+    complete_listing_sale(fish, listing, buyer.key(), price, royalty);
+    move_profile_fish(
+        ctx.accounts.seller_profile.as_mut(),
+        ctx.accounts.buyer_profile.as_mut(),
+        fish.id,
+    );
+    if let Some(leaderboard) = ctx.accounts.leaderboard.as_mut() {
+        leaderboard.record(fish, Clock::get()?.unix_timestamp);
+    }
+
+    let listing_key = listing.key();
+    refund_bids(listing, &listing_key, None, ctx.remaining_accounts)?;
+    close_listing_if_settled(listing, seller)
+}
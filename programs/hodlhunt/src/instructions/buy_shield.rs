@@ -0,0 +1,73 @@
+use crate::constants::shields;
+use crate::errors::ErrorCode;
+use crate::state::Fish;
+use crate::{events::*, instructions::BuyShield, utils::*};
+use anchor_lang::prelude::*;
+
+/// Buys hunting protection for `duration` seconds. The price is a share of the fish's
+/// value scaled by duration and ocean mode, split between the pool and admin. Shields
+/// cannot overlap existing protection, must respect a cooldown after the previous
+/// shield lapses, and are capped in total length per shield season.
+pub fn handle(ctx: Context<BuyShield>, duration: i64) -> Result<()> {
+    let ocean = &mut ctx.accounts.ocean;
+    let fish = &mut ctx.accounts.fish;
+    let vault = &ctx.accounts.vault;
+    let owner = &ctx.accounts.owner;
+    let admin = &ctx.accounts.admin;
+    let system_program = &ctx.accounts.system_program;
+
+    let now = Clock::get()?.unix_timestamp;
+
+    fish.ensure_alive()?;
+    fish.ensure_owned_by(&owner.key())?;
+    fish.ensure_not_trade_locked()?;
+    require!(
+        (shields::MIN_DURATION_SECONDS..=shields::MAX_DURATION_SECONDS).contains(&duration),
+        ErrorCode::InvalidShieldDuration
+    );
+
+    let still_protected = fish.refresh_protection(now);
+    let quote = shield_breakdown(ocean, fish, duration, now);
+    require!(
+        !still_protected && now >= quote.available_at,
+        ErrorCode::ShieldOnCooldown
+    );
+    require!(
+        duration <= quote.season_seconds_left,
+        ErrorCode::ShieldCapReached
+    );
+    require!(owner.lamports() >= quote.cost, ErrorCode::InsufficientFunds);
+
+    transfer_from_signer(
+        &owner.to_account_info(),
+        vault,
+        &system_program.to_account_info(),
+        quote.pool_fee,
+    )?;
+    transfer_from_signer(
+        &owner.to_account_info(),
+        admin,
+        &system_program.to_account_info(),
+        quote.admin_fee,
+    )?;
+    ocean.balance_fishes = ocean.balance_fishes.saturating_add(quote.pool_fee);
+
+    fish.shield_seconds_used = fish.shield_seconds_in_season(now) + duration;
+    fish.shield_season = Fish::shield_season_at(now);
+    fish.last_shield_at = now;
+    fish.is_protected = true;
+    fish.protection_ends_at = quote.protection_ends_at;
+
+    emit!(ShieldPurchased {
+        fish_id: fish.id,
+        owner: owner.key(),
+        duration,
+        cost: quote.cost,
+        admin_fee: quote.admin_fee,
+        pool_fee: quote.pool_fee,
+        protection_ends_at: fish.protection_ends_at,
+        season_seconds_used: fish.shield_seconds_used,
+    });
+
+    Ok(())
+}
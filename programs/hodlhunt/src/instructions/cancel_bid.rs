@@ -0,0 +1,34 @@
+use super::common::close_listing_if_settled;
+use crate::errors::ErrorCode;
+use crate::{events::*, instructions::CancelBid};
+use anchor_lang::prelude::*;
+
+/// Withdraws a bid and refunds its escrow to the bidder. The highest bid on a live
+/// auction is locked until settlement; once the listing is gone or the fish has died,
+/// every bid can be withdrawn.
+pub fn handle(ctx: Context<CancelBid>) -> Result<()> {
+    let fish = &ctx.accounts.fish;
+    let listing = &mut ctx.accounts.listing;
+    let bid = &ctx.accounts.bid;
+    let seller = &ctx.accounts.seller;
+
+    let is_highest = listing.highest_bidder == bid.bidder;
+    require!(
+        !(is_highest && listing.is_auction() && listing.is_live(fish)),
+        ErrorCode::BidLocked
+    );
+
+    if is_highest {
+        listing.highest_bid = 0;
+        listing.highest_bidder = Pubkey::default();
+    }
+    listing.open_bids = listing.open_bids.saturating_sub(1);
+
+    emit!(BidRefunded {
+        fish_id: listing.fish_id,
+        bidder: bid.bidder,
+        amount: bid.amount,
+    });
+
+    close_listing_if_settled(listing, seller)
+}
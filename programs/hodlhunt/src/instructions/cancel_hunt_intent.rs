@@ -0,0 +1,14 @@
+use crate::{events::*, instructions::CancelHuntIntent};
+use anchor_lang::prelude::*;
+
+/// Withdraws a pending hunt intent, refunding the tip and rent to the owner.
+pub fn handle(ctx: Context<CancelHuntIntent>) -> Result<()> {
+    let intent = &ctx.accounts.intent;
+
+    emit!(HuntIntentCancelled {
+        hunter_id: intent.hunter_id,
+        owner: intent.owner,
+    });
+
+    Ok(())
+}
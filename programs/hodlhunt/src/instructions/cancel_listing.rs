@@ -0,0 +1,33 @@
+use super::common::{close_listing_if_settled, refund_bids};
+use crate::errors::ErrorCode;
+use crate::{events::*, instructions::CancelListing, Fish};
+use anchor_lang::prelude::*;
+
+/// Withdraws a listing, unfreezing the fish if it is still locked by it. Live auctions
+/// that already hold a bid cannot be cancelled. Bids passed as `(bid, bidder)` pairs in
+/// remaining accounts are refunded, and the listing closes once none remain.
+pub fn handle<'info>(ctx: Context<'_, '_, 'info, 'info, CancelListing<'info>>) -> Result<()> {
+    let fish = &mut ctx.accounts.fish;
+    let listing = &mut ctx.accounts.listing;
+    let seller = &ctx.accounts.seller;
+
+    if listing.is_live(fish) {
+        require!(
+            !listing.is_auction() || listing.highest_bid == 0,
+            ErrorCode::ListingHasBids
+        );
+        fish.trade_lock = Fish::TRADE_LOCK_NONE;
+    }
+    listing.is_active = false;
+
+    let listing_key = listing.key();
+    refund_bids(listing, &listing_key, None, ctx.remaining_accounts)?;
+
+    emit!(ListingCancelled {
+        fish_id: listing.fish_id,
+        seller: listing.seller,
+        open_bids: listing.open_bids,
+    });
+
+    close_listing_if_settled(listing, &seller.to_account_info())
+}
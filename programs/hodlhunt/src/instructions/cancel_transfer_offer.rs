@@ -0,0 +1,21 @@
+use crate::{events::*, instructions::CancelTransferOffer, Fish};
+use anchor_lang::prelude::*;
+
+/// Withdraws an open transfer offer, returning its rent to the seller and unfreezing
+/// the fish if it is still locked by the offer.
+pub fn handle(ctx: Context<CancelTransferOffer>) -> Result<()> {
+    let fish = &mut ctx.accounts.fish;
+    let offer = &ctx.accounts.offer;
+
+    if fish.owner == offer.seller && fish.trade_lock == Fish::TRADE_LOCK_OFFER {
+        fish.trade_lock = Fish::TRADE_LOCK_NONE;
+    }
+
+    emit!(TransferOfferCancelled {
+        fish_id: offer.fish_id,
+        seller: offer.seller,
+        recipient: offer.recipient,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,16 @@
+use crate::constants::hunting;
+use crate::{instructions::CheckHunt, utils::*, views::HuntCheck};
+use anchor_lang::prelude::*;
+
+/// Read-only view that evaluates every `hunt_fish` rule for the pair right now and returns
+/// the failing reasons together with the times at which time-based rules will pass.
+pub fn handle(ctx: Context<CheckHunt>, expected_prey_share: u64) -> Result<HuntCheck> {
+    let now = Clock::get()?.unix_timestamp;
+    Ok(evaluate_hunt(
+        &ctx.accounts.hunter,
+        &ctx.accounts.prey,
+        expected_prey_share,
+        hunting::DEFAULT_SLIPPAGE_BPS,
+        now,
+    ))
+}
@@ -0,0 +1,27 @@
+use crate::errors::ErrorCode;
+use crate::{events::*, instructions::ClaimReferralEarnings, utils::*};
+use anchor_lang::prelude::*;
+
+/// Withdraws all unclaimed referral earnings to the referrer.
+pub fn handle(ctx: Context<ClaimReferralEarnings>) -> Result<()> {
+    let referral = &mut ctx.accounts.referral;
+    let referrer = &ctx.accounts.referrer;
+
+    let amount = referral.claimable();
+    require!(amount > 0, ErrorCode::NothingToClaim);
+
+    transfer_lamports(
+        &referral.to_account_info(),
+        &referrer.to_account_info(),
+        amount,
+    )?;
+    referral.total_claimed = referral.total_claimed.saturating_add(amount);
+
+    emit!(ReferralClaimed {
+        referrer: referrer.key(),
+        amount,
+        total_claimed: referral.total_claimed,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,43 @@
+use crate::errors::ErrorCode;
+use crate::{events::*, instructions::ClaimSeasonPrize, utils::*};
+use anchor_lang::prelude::*;
+
+/// Pays the prize for `rank` to the owner recorded in the final standings once the
+/// finalization window has closed and until the claim window ends. Each rank can be
+/// claimed once.
+pub fn handle(ctx: Context<ClaimSeasonPrize>, rank: u8) -> Result<()> {
+    let season = &mut ctx.accounts.season;
+    let owner = &ctx.accounts.owner;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(
+        now >= season.finalization_closes_at(),
+        ErrorCode::SeasonNotFinalized
+    );
+    require!(
+        now < season.claims_close_at(),
+        ErrorCode::SeasonClaimsClosed
+    );
+    require!(rank < season.winner_count, ErrorCode::NotSeasonWinner);
+    let winner = season.winners[rank as usize];
+    require_keys_eq!(winner.owner, owner.key(), ErrorCode::NotSeasonWinner);
+    let bit = 1u8 << rank;
+    require!(
+        season.claimed_mask & bit == 0,
+        ErrorCode::PrizeAlreadyClaimed
+    );
+
+    let amount = season.prize_for(rank as usize);
+    season.claimed_mask |= bit;
+    transfer_lamports(&season.to_account_info(), &owner.to_account_info(), amount)?;
+
+    emit!(SeasonPrizeClaimed {
+        season_id: season.season_id,
+        rank,
+        fish_id: winner.fish_id,
+        owner: owner.key(),
+        amount,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,17 @@
+use crate::state::AutoFeed;
+use crate::{events::*, instructions::CloseAutoFeed};
+use anchor_lang::prelude::*;
+
+/// Closes the auto-feed escrow, refunding the remaining budget and rent to the owner.
+pub fn handle(ctx: Context<CloseAutoFeed>) -> Result<()> {
+    let auto_feed = &ctx.accounts.auto_feed;
+
+    emit!(AutoFeedWithdrawn {
+        fish_id: auto_feed.fish_id,
+        owner: auto_feed.owner,
+        amount: AutoFeed::available(&auto_feed.to_account_info())?,
+        closed: true,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use super::common::{close_listing_if_settled, refund_bids};
+use crate::errors::ErrorCode;
+use crate::{events::*, instructions::CloseListing};
+use anchor_lang::prelude::*;
+
+/// Clears a listing that is no longer live, e.g. after a sale, so the fish can be
+/// listed again. Either the seller or the fish's current owner may refund the bids left
+/// behind, passed as `(bid, bidder)` pairs in remaining accounts; the listing closes to
+/// the seller once none remain.
+pub fn handle<'info>(ctx: Context<'_, '_, 'info, 'info, CloseListing<'info>>) -> Result<()> {
+    let fish = &ctx.accounts.fish;
+    let listing = &mut ctx.accounts.listing;
+    let seller = &ctx.accounts.seller;
+    let authority = &ctx.accounts.authority;
+
+    require!(
+        authority.key() == listing.seller || authority.key() == fish.owner,
+        ErrorCode::NotFishOwner
+    );
+    require!(!listing.is_live(fish), ErrorCode::ListingStillLive);
+    listing.is_active = false;
+
+    let listing_key = listing.key();
+    refund_bids(listing, &listing_key, None, ctx.remaining_accounts)?;
+
+    emit!(ListingClosed {
+        fish_id: listing.fish_id,
+        seller: listing.seller,
+        closed_by: authority.key(),
+        open_bids: listing.open_bids,
+    });
+
+    close_listing_if_settled(listing, seller)
+}
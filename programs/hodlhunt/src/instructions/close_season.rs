@@ -0,0 +1,20 @@
+use crate::errors::ErrorCode;
+use crate::{events::*, instructions::CloseSeason};
+use anchor_lang::prelude::*;
+
+/// Closes a season once its claim window has ended, sweeping unclaimed prizes and the
+/// account rent to the admin. Admin only.
+pub fn handle(ctx: Context<CloseSeason>) -> Result<()> {
+    let season = &ctx.accounts.season;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(now >= season.claims_close_at(), ErrorCode::SeasonClaimsOpen);
+
+    emit!(SeasonClosed {
+        season_id: season.season_id,
+        claimed_mask: season.claimed_mask,
+        swept: season.to_account_info().lamports(),
+    });
+
+    Ok(())
+}
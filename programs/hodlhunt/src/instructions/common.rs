@@ -2,8 +2,10 @@ use crate::constants::fees;
 use crate::seeds::{derive_name_registry_pda, SEED_NAME};
 use crate::state::*;
 use crate::utils::*;
+use crate::views::FeedQuote;
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program as spl_prog;
+use anchor_lang::AccountsClose;
 
 pub struct CreateParams<'info> {
     pub ocean: &'info mut Account<'info, Ocean>,
@@ -14,21 +16,33 @@ pub struct CreateParams<'info> {
     pub system_program: &'info Program<'info, System>,
 }
 
-/// Splits the provided deposit into admin and pool fees, ensuring the payer has
-/// sufficient lamports and performing the necessary transfers to the vault and admin.
+/// Optional recipients that take a slice of an admin fee before the rest reaches the admin.
+/// A referral is only paid when it matches `bound_referrer`, the referrer recorded on the
+/// player's profile.
+pub struct AdminFeeCuts<'a, 'info> {
+    pub referral: Option<&'a mut Account<'info, Referral>>,
+    pub bound_referrer: Option<Pubkey>,
+    pub season: Option<&'a mut Account<'info, Season>>,
+}
+
+/// Splits the provided deposit into admin and pool fees, applying `fee_discount_bps` to
+/// both, ensuring the payer has sufficient lamports and performing the necessary
+/// transfers to the vault and admin (sharing the admin fee with an optional referrer
+/// and season prize pot).
 pub fn apply_creation_finance<'info>(
     owner: &Signer<'info>,
     vault: &AccountInfo<'info>,
     admin: &AccountInfo<'info>,
     system_program: &Program<'info, System>,
     deposit: u64,
+    fee_discount_bps: u64,
+    cuts: AdminFeeCuts<'_, 'info>,
 ) -> Result<(u64, u64, u64)> {
     require!(
         deposit >= fees::MIN_DEPOSIT_LAMPORTS,
         crate::errors::ErrorCode::MinimumDeposit
     );
-    let admin_fee = deposit / fees::CREATION_FEE_DIVISOR;
-    let pool_fee = deposit / fees::CREATION_FEE_DIVISOR;
+    let (admin_fee, pool_fee) = creation_fees(deposit, fee_discount_bps);
     let total_cost = deposit + admin_fee + pool_fee;
     require!(
         owner.lamports() >= total_cost,
@@ -46,19 +60,152 @@ pub fn apply_creation_finance<'info>(
         ],
     )?;
 
-    let ix_admin = spl_prog::system_instruction::transfer(&owner.key(), &admin.key(), admin_fee);
-    spl_prog::program::invoke(
-        &ix_admin,
-        &[
-            owner.to_account_info(),
-            admin.to_account_info(),
-            system_program.to_account_info(),
-        ],
-    )?;
+    pay_admin_fee(owner, admin, cuts, system_program, admin_fee)?;
 
     Ok((admin_fee, pool_fee, deposit))
 }
 
+/// Pays `admin_fee` from `payer` to the admin, diverting the referral cut into the bound
+/// referrer's referral PDA and the pot cut into a running season when supplied.
+/// Returns the total diverted away from the admin.
+pub fn pay_admin_fee<'info>(
+    payer: &Signer<'info>,
+    admin: &AccountInfo<'info>,
+    cuts: AdminFeeCuts<'_, 'info>,
+    system_program: &Program<'info, System>,
+    admin_fee: u64,
+) -> Result<u64> {
+    let referral_cut = match cuts.referral {
+        Some(referral) => {
+            require!(
+                cuts.bound_referrer == Some(referral.referrer)
+                    && referral.referrer != payer.key(),
+                crate::errors::ErrorCode::InvalidReferral
+            );
+            let cut = admin_fee * fees::REFERRAL_CUT_BPS / fees::BASIS_POINTS_DIVISOR;
+            transfer_from_signer(
+                &payer.to_account_info(),
+                &referral.to_account_info(),
+                &system_program.to_account_info(),
+                cut,
+            )?;
+            referral.referred_actions = referral.referred_actions.saturating_add(1);
+            referral.total_earned = referral.total_earned.saturating_add(cut);
+
+            emit!(crate::ReferralRewarded {
+                referrer: referral.referrer,
+                payer: payer.key(),
+                admin_fee,
+                amount: cut,
+                total_earned: referral.total_earned,
+            });
+            cut
+        }
+        None => 0,
+    };
+
+    let season_cut = match cuts.season {
+        Some(season) if season.is_running(Clock::get()?.unix_timestamp) => {
+            let cut = season.pot_cut(admin_fee);
+            transfer_from_signer(
+                &payer.to_account_info(),
+                &season.to_account_info(),
+                &system_program.to_account_info(),
+                cut,
+            )?;
+            season.prize_pot = season.prize_pot.saturating_add(cut);
+            cut
+        }
+        _ => 0,
+    };
+
+    let diverted = referral_cut + season_cut;
+    transfer_from_signer(
+        &payer.to_account_info(),
+        admin,
+        &system_program.to_account_info(),
+        admin_fee - diverted,
+    )?;
+    Ok(diverted)
+}
+
+/// Unlocks the achievements `fish` has newly earned, plus the `granted` ones earned by
+/// the action itself, when its owner's achievements account is supplied, emitting
+/// `AchievementUnlocked` for each.
+pub fn unlock_achievements(
+    achievements: Option<&mut Account<Achievements>>,
+    ocean: &Ocean,
+    fish: &Fish,
+    granted: u16,
+    current_time: i64,
+) {
+    if let Some(achievements) = achievements {
+        let earned = earned_achievements(ocean, fish, current_time) | granted;
+        for achievement in 0..crate::constants::achievements::MAX_ACHIEVEMENTS as u8 {
+            if earned & (1 << achievement) != 0 && achievements.unlock(achievement, current_time)
+            {
+                emit!(crate::AchievementUnlocked {
+                    owner: achievements.owner,
+                    fish_id: fish.id,
+                    achievement,
+                    unlocked_at: current_time,
+                });
+            }
+        }
+    }
+}
+
+/// Enters `fish` into the season when a running one is supplied, then records the value
+/// change of the action for whichever season the fish is in. Returns whether the
+/// action counted towards a season.
+pub fn track_season(
+    season: Option<&Account<Season>>,
+    ocean: &Ocean,
+    fish: &mut Fish,
+    value_before: u64,
+    contributed: u64,
+    current_time: i64,
+) -> bool {
+    if let Some(season) = season {
+        if season.is_running(current_time) {
+            fish.enter_season(season.season_id, season.end_time, value_before);
+        }
+    }
+    let value_after = share_to_value(ocean, fish.share);
+    fish.record_season_activity(value_after, contributed, 0, current_time)
+}
+
+/// Optional accounts that follow a fish's standing after it is fed.
+pub struct FeedingTrackers<'a, 'info> {
+    pub season: Option<&'a Account<'info, Season>>,
+    pub leaderboard: Option<&'a mut Account<'info, Leaderboard>>,
+    pub achievements: Option<&'a mut Account<'info, Achievements>>,
+}
+
+/// Records an applied feeding of `contributed` lamports with the season, leaderboard and
+/// achievements in `trackers`, shared by every path that feeds a fish.
+pub fn record_feeding(
+    trackers: FeedingTrackers,
+    ocean: &Ocean,
+    fish: &mut Fish,
+    value_before: u64,
+    contributed: u64,
+    current_time: i64,
+) {
+    track_season(
+        trackers.season,
+        ocean,
+        fish,
+        value_before,
+        contributed,
+        current_time,
+    );
+    if let Some(leaderboard) = trackers.leaderboard {
+        leaderboard.record(fish, current_time);
+    }
+    unlock_achievements(trackers.achievements, ocean, fish, 0, current_time);
+}
+
 /// Mints shares for a new or existing fish by increasing the ocean balance and
 /// returning the number of shares granted for the supplied value.
 pub fn mint_fish_share(ocean: &mut Ocean, fish: &mut Fish, value: u64) -> u64 {
@@ -69,6 +216,227 @@ pub fn mint_fish_share(ocean: &mut Ocean, fish: &mut Fish, value: u64) -> u64 {
     share
 }
 
+/// Applies an already paid feeding to the ocean and fish: credits the vault balance, mints
+/// the quoted shares and any streak bonus, resets hunger, marks and cooldowns, and emits
+/// `FishFed` (plus `StreakMilestone` when a milestone is reached).
+pub fn apply_feeding(ocean: &mut Ocean, fish: &mut Fish, quote: &FeedQuote, now: i64) {
+    ocean.balance_fishes = ocean
+        .balance_fishes
+        .saturating_add(quote.feeding_amount + quote.pool_fee);
+
+    let added_share = quote.shares_minted + quote.streak_bonus_shares;
+    fish.share = fish.share.saturating_add(added_share);
+    ocean.total_shares = ocean.total_shares.saturating_add(added_share);
+
+    fish.refresh_protection(now);
+    fish.feeding_streak = quote.feeding_streak;
+    fish.best_feeding_streak = fish.best_feeding_streak.max(fish.feeding_streak);
+    fish.last_fed_at = now;
+    fish.marked_by_hunter_id = 0;
+    fish.mark_placed_at = 0;
+    fish.mark_expires_at = 0;
+    fish.mark_cost = 0;
+    fish.can_hunt_after = now + Fish::FEEDING_COOLDOWN;
+    fish.received_from_hunt_value = 0;
+
+    emit!(crate::FishFed {
+        fish_id: fish.id,
+        owner: fish.owner,
+        added_share,
+        base_cost: quote.feeding_amount,
+        admin_fee: quote.admin_fee,
+        pool_fee: quote.pool_fee,
+        new_share: fish.share,
+        new_value: share_to_value(ocean, fish.share),
+        fee_discount_bps: quote.loyalty_discount_bps,
+        feeding_streak: fish.feeding_streak,
+    });
+
+    if quote.streak_milestone {
+        emit!(crate::StreakMilestone {
+            fish_id: fish.id,
+            owner: fish.owner,
+            feeding_streak: fish.feeding_streak,
+            bonus_value: quote.streak_bonus_value,
+            bonus_shares: quote.streak_bonus_shares,
+        });
+    }
+}
+
+/// Authorizes `signer` to act on `fish` as its owner, as a live session key of the
+/// owner, or as a delegate holding `permission`. Lamports spent by a session or delegate
+/// are charged against its budget or cap.
+pub fn authorize_fish_action(
+    fish: &Fish,
+    signer: &Pubkey,
+    delegation: Option<&mut Account<Delegation>>,
+    session: Option<&mut Account<SessionKey>>,
+    permission: u8,
+    spend: u64,
+    now: i64,
+) -> Result<()> {
+    if fish.owner == *signer {
+        return Ok(());
+    }
+    if let Some(session) = session {
+        require!(
+            session.owner == fish.owner && session.session_key == *signer,
+            crate::errors::ErrorCode::NotFishOwner
+        );
+        return session.authorize(spend, now);
+    }
+    let delegation = delegation.ok_or(crate::errors::ErrorCode::NotFishOwner)?;
+    require!(
+        delegation.owner == fish.owner && delegation.delegate == *signer,
+        crate::errors::ErrorCode::NotFishOwner
+    );
+    delegation.authorize(permission, spend, now)
+}
+
+/// Settles a hunt that already passed validation: moves the prey share to the hunter,
+/// pool and admin, updates hunter cooldowns and counters, and emits `FishHunted`. The
+/// caller releases the prey's name registry afterwards.
+pub fn resolve_hunt<'info>(
+    ocean: &mut Account<'info, Ocean>,
+    hunter: &mut Fish,
+    prey: &mut Fish,
+    vault: &AccountInfo<'info>,
+    admin: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    current_time: i64,
+) -> Result<()> {
+    hunter.refresh_protection(current_time);
+    prey.refresh_protection(current_time);
+
+    let quote = hunt_breakdown(ocean, hunter, prey, current_time);
+    let bite_share = quote.bite_share;
+    let to_hunter = quote.to_hunter;
+    let to_pool = quote.to_pool;
+    let to_admin_share = quote.to_admin;
+    let to_pool_value = quote.to_pool_value;
+    let to_admin_value = quote.to_admin_value;
+
+    prey.share = 0;
+    // A dead fish can no longer be sold; releasing the lock invalidates any listing.
+    prey.trade_lock = Fish::TRADE_LOCK_NONE;
+    hunter.share = hunter.share.saturating_add(to_hunter);
+
+    ocean.total_shares = ocean
+        .total_shares
+        .checked_sub(to_admin_share + to_pool)
+        .ok_or(crate::errors::ErrorCode::MathOverflow)?;
+    ocean.balance_fishes = ocean
+        .balance_fishes
+        .checked_sub(to_admin_value)
+        .ok_or(crate::errors::ErrorCode::MathOverflow)?;
+
+    if to_admin_value > 0 {
+        transfer_to_admin(
+            ocean,
+            &ocean.key(),
+            vault,
+            admin,
+            system_program,
+            to_admin_value,
+        )?;
+    }
+
+    let received_from_hunt_value = quote.received_from_hunt_value;
+
+    hunter.last_hunt_at = current_time;
+    hunter.can_hunt_after = current_time + Fish::POST_HUNT_COOLDOWN;
+    if quote.counts_as_feeding {
+        hunter.last_fed_at = current_time;
+        hunter.received_from_hunt_value = 0;
+    } else {
+        hunter.received_from_hunt_value = received_from_hunt_value;
+    }
+
+    hunter.total_hunts = hunter.total_hunts.saturating_add(1);
+    hunter.total_hunt_income = hunter
+        .total_hunt_income
+        .saturating_add(received_from_hunt_value);
+
+    ocean.total_fish_count = ocean.total_fish_count.saturating_sub(1);
+
+    emit!(crate::FishHunted {
+        hunter_id: hunter.id,
+        prey_id: prey.id,
+        hunter_owner: hunter.owner,
+        prey_owner: prey.owner,
+        bite_share,
+        to_hunter,
+        to_pool,
+        to_admin: to_admin_share,
+        enhanced: false,
+        hunter_new_share: hunter.share,
+        prey_new_share: prey.share,
+        received_from_hunt_value,
+        to_admin_value,
+        to_pool_value,
+        bite_percent: 100,
+        bite_fee_percent: quote.fee_bps / 100,
+        bite_fee: to_pool + to_admin_share,
+        size_tier: quote.size_tier,
+        size_ratio_bps: quote.size_ratio_bps,
+    });
+
+    Ok(())
+}
+
+/// Settles a hunt in which the prey escaped: the hunter pays the failed-hunt fee out of
+/// its share, split between pool and admin, its hunting cooldown is burned and
+/// `HuntFailed` is emitted. The prey is left untouched. Returns the value of the fee.
+pub fn resolve_failed_hunt<'info>(
+    ocean: &mut Ocean,
+    hunter: &mut Fish,
+    prey: &Fish,
+    vault: &AccountInfo<'info>,
+    admin: &AccountInfo<'info>,
+    roll: &EscapeRoll,
+    current_time: i64,
+) -> Result<u64> {
+    hunter.refresh_protection(current_time);
+
+    let quote = hunt_breakdown(ocean, hunter, prey, current_time);
+    let fee_share = quote.failed_hunt_fee_share;
+    let fee_value = share_to_value(ocean, fee_share);
+    let to_admin = fee_share / fees::FEE_SPLIT_DIVISOR;
+    let to_pool = fee_share - to_admin;
+    let to_admin_value = share_to_value(ocean, to_admin);
+
+    hunter.share = hunter.share.saturating_sub(fee_share);
+    ocean.total_shares = ocean
+        .total_shares
+        .checked_sub(fee_share)
+        .ok_or(crate::errors::ErrorCode::MathOverflow)?;
+    ocean.balance_fishes = ocean
+        .balance_fishes
+        .checked_sub(to_admin_value)
+        .ok_or(crate::errors::ErrorCode::MathOverflow)?;
+    transfer_lamports(vault, admin, to_admin_value)?;
+
+    hunter.last_hunt_at = current_time;
+    hunter.can_hunt_after = current_time + Fish::POST_HUNT_COOLDOWN;
+
+    emit!(crate::HuntFailed {
+        hunter_id: hunter.id,
+        prey_id: prey.id,
+        hunter_owner: hunter.owner,
+        prey_owner: prey.owner,
+        escape_chance_bps: roll.chance_bps,
+        roll_bps: roll.roll_bps,
+        fee_share,
+        to_pool,
+        to_admin,
+        to_admin_value,
+        hunter_new_share: hunter.share,
+        can_hunt_after: hunter.can_hunt_after,
+    });
+
+    Ok(fee_value)
+}
+
 /// Initializes core metadata for a newly created fish, setting protection periods,
 /// cooldowns, and counters while updating ocean aggregates.
 pub fn init_new_fish_meta(ocean: &mut Ocean, fish: &mut Fish, owner: Pubkey, name: &str) {
@@ -188,3 +556,261 @@ pub fn release_name_if_dead<'info>(
 
     Ok(())
 }
+
+/// Splits a marketplace sale price into the admin royalty and the seller's proceeds.
+pub fn market_royalty(price: u64) -> (u64, u64) {
+    let royalty = price
+        .saturating_mul(fees::MARKET_ROYALTY_BPS)
+        .saturating_div(fees::BASIS_POINTS_DIVISOR);
+    (royalty, price.saturating_sub(royalty))
+}
+
+/// Moves `fish_id` from the previous owner's profile to the new owner's, for whichever
+/// of the two profiles is supplied. A full receiving profile is left as is, so fish
+/// pushed onto a player cannot block the handover; `sync_profile` lists them later.
+pub fn move_profile_fish(
+    from: Option<&mut Account<PlayerProfile>>,
+    to: Option<&mut Account<PlayerProfile>>,
+    fish_id: u64,
+) {
+    if let Some(from) = from {
+        from.remove_fish(fish_id);
+    }
+    if let Some(to) = to {
+        to.try_add_fish(fish_id);
+    }
+}
+
+/// Hands a listed fish over to the buyer, unfreezes it and deactivates the listing.
+pub fn complete_listing_sale(
+    fish: &mut Fish,
+    listing: &mut Listing,
+    buyer: Pubkey,
+    price: u64,
+    royalty: u64,
+) {
+    fish.owner = buyer;
+    fish.trade_lock = Fish::TRADE_LOCK_NONE;
+    listing.is_active = false;
+
+    emit!(crate::FishSold {
+        fish_id: fish.id,
+        seller: listing.seller,
+        buyer,
+        price,
+        royalty,
+        to_seller: price.saturating_sub(royalty),
+        via_auction: listing.is_auction(),
+    });
+    emit!(crate::FishTransferred {
+        fish_id: fish.id,
+        from_owner: listing.seller,
+        to_owner: buyer,
+    });
+}
+
+/// Refunds escrowed bids supplied as `(bid, bidder)` pairs in remaining accounts, closing
+/// each bid PDA back to its bidder and releasing it from the listing's open bid count.
+/// `settled_bid` is the bid consumed by the sale in the same instruction; it is still
+/// program-owned until the instruction exits, so it must not be refunded again.
+pub fn refund_bids<'info>(
+    listing: &mut Listing,
+    listing_key: &Pubkey,
+    settled_bid: Option<&Pubkey>,
+    remaining_accounts: &'info [AccountInfo<'info>],
+) -> Result<()> {
+    let pairs = remaining_accounts.chunks_exact(2);
+    require!(
+        pairs.remainder().is_empty(),
+        crate::errors::ErrorCode::InvalidBid
+    );
+    let bid_keys: Vec<Pubkey> = remaining_accounts
+        .iter()
+        .step_by(2)
+        .map(|info| info.key())
+        .collect();
+    check_refund_batch(&bid_keys, settled_bid)?;
+
+    for pair in pairs {
+        let bid_info = &pair[0];
+        let bidder_info = &pair[1];
+        let bid: Account<'info, Bid> = Account::try_from(bid_info)?;
+        require_keys_eq!(bid.listing, *listing_key, crate::errors::ErrorCode::InvalidBid);
+        require_keys_eq!(bid.bidder, bidder_info.key(), crate::errors::ErrorCode::InvalidBid);
+
+        emit!(crate::BidRefunded {
+            fish_id: listing.fish_id,
+            bidder: bid.bidder,
+            amount: bid.amount,
+        });
+        close_program_account(bid_info, bidder_info)?;
+        listing.open_bids = listing
+            .open_bids
+            .checked_sub(1)
+            .ok_or(crate::errors::ErrorCode::InvalidBid)?;
+    }
+
+    Ok(())
+}
+
+/// Rejects a refund batch that includes the settled bid or lists a bid twice, so each
+/// entry releases exactly one distinct escrow from the open bid count.
+pub fn check_refund_batch(bid_keys: &[Pubkey], settled_bid: Option<&Pubkey>) -> Result<()> {
+    for (index, key) in bid_keys.iter().enumerate() {
+        require!(
+            Some(key) != settled_bid && !bid_keys[..index].contains(key),
+            crate::errors::ErrorCode::InvalidBid
+        );
+    }
+    Ok(())
+}
+
+/// Closes an inactive listing back to its seller once no escrowed bids reference it.
+pub fn close_listing_if_settled<'info>(
+    listing: &Account<'info, Listing>,
+    seller: &AccountInfo<'info>,
+) -> Result<()> {
+    if !listing.is_active && listing.open_bids == 0 {
+        listing.close(seller.clone())?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leaked_account(key: Pubkey) -> AccountInfo<'static> {
+        AccountInfo::new(
+            Box::leak(Box::new(key)),
+            false,
+            true,
+            Box::leak(Box::new(0u64)),
+            Box::leak(Vec::new().into_boxed_slice()),
+            Box::leak(Box::new(crate::ID)),
+            false,
+            0,
+        )
+    }
+
+    fn refund_pairs(bid_keys: &[Pubkey]) -> &'static [AccountInfo<'static>] {
+        let accounts: Vec<AccountInfo<'static>> = bid_keys
+            .iter()
+            .flat_map(|key| [leaked_account(*key), leaked_account(Pubkey::new_unique())])
+            .collect();
+        Box::leak(accounts.into_boxed_slice())
+    }
+
+    fn listing_with_bids(open_bids: u32) -> Listing {
+        Listing {
+            open_bids,
+            ..Listing::default()
+        }
+    }
+
+    #[test]
+    fn refund_batch_accepts_distinct_losing_bids() {
+        let settled = Pubkey::new_unique();
+        let bids = [Pubkey::new_unique(), Pubkey::new_unique()];
+        assert!(check_refund_batch(&bids, Some(&settled)).is_ok());
+        assert!(check_refund_batch(&bids, None).is_ok());
+        assert!(check_refund_batch(&[], Some(&settled)).is_ok());
+    }
+
+    #[test]
+    fn refund_batch_rejects_settled_and_repeated_bids() {
+        let settled = Pubkey::new_unique();
+        let other = Pubkey::new_unique();
+        assert!(check_refund_batch(&[other, settled], Some(&settled)).is_err());
+        assert!(check_refund_batch(&[other, other], None).is_err());
+    }
+
+    #[test]
+    fn refund_bids_rejects_winning_bid_before_touching_accounts() {
+        let settled = Pubkey::new_unique();
+        let mut listing = listing_with_bids(2);
+        let accounts = refund_pairs(&[Pubkey::new_unique(), settled]);
+        let result = refund_bids(&mut listing, &Pubkey::new_unique(), Some(&settled), accounts);
+        assert!(result.is_err());
+        assert_eq!(listing.open_bids, 2);
+    }
+
+    #[test]
+    fn refund_bids_rejects_duplicate_bid_before_touching_accounts() {
+        let bid = Pubkey::new_unique();
+        let mut listing = listing_with_bids(3);
+        let accounts = refund_pairs(&[bid, bid]);
+        assert!(refund_bids(&mut listing, &Pubkey::new_unique(), None, accounts).is_err());
+        assert_eq!(listing.open_bids, 3);
+    }
+
+    #[test]
+    fn refund_bids_rejects_unpaired_accounts() {
+        let mut listing = listing_with_bids(1);
+        let accounts = Box::leak(vec![leaked_account(Pubkey::new_unique())].into_boxed_slice());
+        assert!(refund_bids(&mut listing, &Pubkey::new_unique(), None, accounts).is_err());
+        assert_eq!(listing.open_bids, 1);
+    }
+
+    fn ocean(total_shares: u64, balance_fishes: u64) -> Ocean {
+        Ocean {
+            admin: Pubkey::default(),
+            total_fish_count: 2,
+            total_shares,
+            balance_fishes,
+            vault_bump: 0,
+            last_feeding_update: 0,
+            next_fish_id: 3,
+            vault: Pubkey::default(),
+            is_storm: false,
+            feeding_percentage: 0,
+            storm_probability_bps: 0,
+            last_cycle_mode: 0,
+            cycle_start_time: 0,
+            next_mode_change_time: 0,
+        }
+    }
+
+    fn funded_account(lamports: u64) -> AccountInfo<'static> {
+        let account = leaked_account(Pubkey::new_unique());
+        **account.try_borrow_mut_lamports().unwrap() = lamports;
+        account
+    }
+
+    #[test]
+    fn escaped_hunt_charges_the_failed_hunt_fee() {
+        let mut ocean = ocean(3_000_000, 3_000_000_000);
+        let mut hunter = Fish {
+            id: 1,
+            share: 2_000_000,
+            ..Fish::default()
+        };
+        let prey = Fish {
+            id: 2,
+            share: 1_000_000,
+            ..Fish::default()
+        };
+        let vault = funded_account(ocean.balance_fishes);
+        let admin = funded_account(0);
+        let escaped = EscapeRoll {
+            chance_bps: fees::BASIS_POINTS_DIVISOR,
+            roll_bps: 0,
+        };
+        assert!(escaped.escaped());
+
+        let now = 1_000;
+        let fee_share = hunt_breakdown(&ocean, &hunter, &prey, now).failed_hunt_fee_share;
+        let fee_value =
+            resolve_failed_hunt(&mut ocean, &mut hunter, &prey, &vault, &admin, &escaped, now)
+                .unwrap();
+
+        assert!(fee_share > 0);
+        assert_eq!(fee_value, 40_000_000);
+        assert_eq!(hunter.share, 2_000_000 - fee_share);
+        assert_eq!(ocean.total_shares, 3_000_000 - fee_share);
+        assert!(admin.lamports() > 0);
+        assert_eq!(vault.lamports(), ocean.balance_fishes);
+        assert_eq!(hunter.last_hunt_at, now);
+        assert_eq!(hunter.can_hunt_after, now + Fish::POST_HUNT_COOLDOWN);
+    }
+}
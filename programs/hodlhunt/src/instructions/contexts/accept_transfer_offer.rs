@@ -0,0 +1,69 @@
+use crate::state::{Fish, Leaderboard, Ocean, PlayerProfile, TransferOffer};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AcceptTransferOffer<'info> {
+    pub ocean: Account<'info, Ocean>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"fish",
+            ocean.key().as_ref(),
+            &fish.id.to_le_bytes()
+        ],
+        bump
+    )]
+    pub fish: Account<'info, Fish>,
+
+    /// Offer is closed on acceptance and its rent returned to the seller
+    #[account(
+        mut,
+        close = seller,
+        seeds = [b"transfer_offer", fish.key().as_ref()],
+        bump = offer.bump,
+        has_one = fish,
+        has_one = seller,
+        has_one = recipient
+    )]
+    pub offer: Account<'info, TransferOffer>,
+
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+
+    #[account(mut)]
+    /// CHECK: Seller recorded in the offer; receives the sale proceeds
+    pub seller: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = admin.key() == ocean.admin
+    )]
+    /// CHECK: Admin must match ocean.admin
+    pub admin: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Optional profile of the seller
+    #[account(
+        mut,
+        seeds = [b"profile", seller.key().as_ref()],
+        bump = seller_profile.bump
+    )]
+    pub seller_profile: Option<Account<'info, PlayerProfile>>,
+
+    /// Optional profile of the recipient
+    #[account(
+        mut,
+        seeds = [b"profile", recipient.key().as_ref()],
+        bump = recipient_profile.bump
+    )]
+    pub recipient_profile: Option<Account<'info, PlayerProfile>>,
+    /// Optional leaderboard refreshed with the fish's new owner
+    #[account(
+        mut,
+        seeds = [b"leaderboard", ocean.key().as_ref()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Option<Account<'info, Leaderboard>>,
+}
@@ -0,0 +1,66 @@
+use crate::state::{Fish, Leaderboard, Listing, Ocean, PlayerProfile};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct BuyListedFish<'info> {
+    pub ocean: Account<'info, Ocean>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"fish",
+            ocean.key().as_ref(),
+            &fish.id.to_le_bytes()
+        ],
+        bump
+    )]
+    pub fish: Account<'info, Fish>,
+
+    #[account(
+        mut,
+        seeds = [b"listing", fish.key().as_ref()],
+        bump = listing.bump,
+        has_one = fish,
+        has_one = seller
+    )]
+    pub listing: Account<'info, Listing>,
+
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    #[account(mut)]
+    /// CHECK: Seller recorded in the listing; receives the sale proceeds
+    pub seller: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = admin.key() == ocean.admin
+    )]
+    /// CHECK: Admin must match ocean.admin
+    pub admin: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Optional profile of the seller
+    #[account(
+        mut,
+        seeds = [b"profile", seller.key().as_ref()],
+        bump = seller_profile.bump
+    )]
+    pub seller_profile: Option<Account<'info, PlayerProfile>>,
+
+    /// Optional profile of the buyer
+    #[account(
+        mut,
+        seeds = [b"profile", buyer.key().as_ref()],
+        bump = buyer_profile.bump
+    )]
+    pub buyer_profile: Option<Account<'info, PlayerProfile>>,
+    /// Optional leaderboard refreshed with the fish's new owner
+    #[account(
+        mut,
+        seeds = [b"leaderboard", ocean.key().as_ref()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Option<Account<'info, Leaderboard>>,
+}
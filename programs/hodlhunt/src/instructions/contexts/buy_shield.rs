@@ -0,0 +1,40 @@
+use crate::state::{Fish, Ocean};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct BuyShield<'info> {
+    #[account(mut)]
+    pub ocean: Account<'info, Ocean>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"fish",
+            ocean.key().as_ref(),
+            &fish.id.to_le_bytes()
+        ],
+        bump,
+        constraint = fish.owner == owner.key()
+    )]
+    pub fish: Account<'info, Fish>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", ocean.key().as_ref()],
+        bump = ocean.vault_bump
+    )]
+    /// CHECK: PDA vault
+    pub vault: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = admin.key() == ocean.admin
+    )]
+    /// CHECK: Admin must match ocean.admin
+    pub admin: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
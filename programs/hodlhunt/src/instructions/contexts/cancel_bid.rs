@@ -0,0 +1,36 @@
+use crate::state::{Bid, Fish, Listing};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CancelBid<'info> {
+    pub fish: Account<'info, Fish>,
+
+    #[account(
+        mut,
+        seeds = [b"listing", fish.key().as_ref()],
+        bump = listing.bump,
+        has_one = fish
+    )]
+    pub listing: Account<'info, Listing>,
+
+    /// Bid escrow is closed back to the bidder, refunding amount and rent
+    #[account(
+        mut,
+        close = bidder,
+        seeds = [b"bid", listing.key().as_ref(), bidder.key().as_ref()],
+        bump = bid.bump,
+        has_one = listing,
+        has_one = bidder
+    )]
+    pub bid: Account<'info, Bid>,
+
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = seller.key() == listing.seller
+    )]
+    /// CHECK: Listing seller; receives the listing rent once the last bid is gone
+    pub seller: AccountInfo<'info>,
+}
@@ -0,0 +1,18 @@
+use crate::state::HuntIntent;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CancelHuntIntent<'info> {
+    /// Intent is closed back to the owner, refunding the tip and rent
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"hunt_intent", intent.hunter.as_ref()],
+        bump = intent.bump,
+        has_one = owner
+    )]
+    pub intent: Account<'info, HuntIntent>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
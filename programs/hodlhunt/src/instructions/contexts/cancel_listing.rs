@@ -0,0 +1,20 @@
+use crate::state::{Fish, Listing};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CancelListing<'info> {
+    #[account(mut)]
+    pub fish: Account<'info, Fish>,
+
+    #[account(
+        mut,
+        seeds = [b"listing", fish.key().as_ref()],
+        bump = listing.bump,
+        has_one = fish,
+        has_one = seller
+    )]
+    pub listing: Account<'info, Listing>,
+
+    #[account(mut)]
+    pub seller: Signer<'info>,
+}
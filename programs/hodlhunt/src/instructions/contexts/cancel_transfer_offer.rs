@@ -0,0 +1,21 @@
+use crate::state::{Fish, TransferOffer};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CancelTransferOffer<'info> {
+    #[account(mut)]
+    pub fish: Account<'info, Fish>,
+
+    #[account(
+        mut,
+        close = seller,
+        seeds = [b"transfer_offer", fish.key().as_ref()],
+        bump = offer.bump,
+        has_one = fish,
+        has_one = seller
+    )]
+    pub offer: Account<'info, TransferOffer>,
+
+    #[account(mut)]
+    pub seller: Signer<'info>,
+}
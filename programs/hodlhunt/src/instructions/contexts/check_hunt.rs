@@ -0,0 +1,8 @@
+use crate::state::Fish;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CheckHunt<'info> {
+    pub hunter: Account<'info, Fish>,
+    pub prey: Account<'info, Fish>,
+}
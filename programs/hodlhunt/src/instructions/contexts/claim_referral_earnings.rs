@@ -0,0 +1,16 @@
+use crate::state::Referral;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ClaimReferralEarnings<'info> {
+    #[account(
+        mut,
+        seeds = [b"referral", referrer.key().as_ref()],
+        bump = referral.bump,
+        has_one = referrer
+    )]
+    pub referral: Account<'info, Referral>,
+
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+}
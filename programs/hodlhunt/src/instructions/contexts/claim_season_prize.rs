@@ -0,0 +1,17 @@
+use crate::state::{Ocean, Season};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ClaimSeasonPrize<'info> {
+    pub ocean: Account<'info, Ocean>,
+
+    #[account(
+        mut,
+        seeds = [b"season", ocean.key().as_ref(), &season.season_id.to_le_bytes()],
+        bump = season.bump
+    )]
+    pub season: Account<'info, Season>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
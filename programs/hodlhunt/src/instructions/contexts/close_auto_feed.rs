@@ -0,0 +1,18 @@
+use crate::state::AutoFeed;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CloseAutoFeed<'info> {
+    /// Escrow is closed back to the owner, refunding the budget and rent
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"auto_feed", auto_feed.fish.as_ref(), owner.key().as_ref()],
+        bump = auto_feed.bump,
+        has_one = owner
+    )]
+    pub auto_feed: Account<'info, AutoFeed>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
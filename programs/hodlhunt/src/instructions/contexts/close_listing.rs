@@ -0,0 +1,23 @@
+use crate::state::{Fish, Listing};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CloseListing<'info> {
+    pub fish: Account<'info, Fish>,
+
+    #[account(
+        mut,
+        seeds = [b"listing", fish.key().as_ref()],
+        bump = listing.bump,
+        has_one = fish,
+        has_one = seller
+    )]
+    pub listing: Account<'info, Listing>,
+
+    #[account(mut)]
+    /// CHECK: Listing seller; receives the listing rent once the last bid is gone
+    pub seller: AccountInfo<'info>,
+
+    /// Listing seller or the fish's current owner
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,21 @@
+use crate::state::{Ocean, Season};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CloseSeason<'info> {
+    pub ocean: Account<'info, Ocean>,
+
+    #[account(
+        mut,
+        seeds = [b"season", ocean.key().as_ref(), &season.season_id.to_le_bytes()],
+        bump = season.bump,
+        close = admin
+    )]
+    pub season: Account<'info, Season>,
+
+    #[account(
+        mut,
+        constraint = admin.key() == ocean.admin
+    )]
+    pub admin: Signer<'info>,
+}
@@ -1,4 +1,4 @@
-use crate::state::{Fish, Ocean};
+use crate::state::{Achievements, Fish, Leaderboard, Ocean, PlayerProfile, Referral, Season};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
@@ -13,7 +13,7 @@ pub struct CreateFish<'info> {
         space = 8 + Fish::INIT_SPACE,
         seeds = [
             b"fish",
-            owner.key().as_ref(),
+            ocean.key().as_ref(),
             &ocean.next_fish_id.to_le_bytes()
         ],
         bump
@@ -43,4 +43,45 @@ pub struct CreateFish<'info> {
     pub admin: AccountInfo<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// Optional referrer account credited with a cut of the admin fee; must be the
+    /// referrer bound to the supplied profile
+    #[account(
+        mut,
+        seeds = [b"referral", referral.referrer.as_ref()],
+        bump = referral.bump
+    )]
+    pub referral: Option<Account<'info, Referral>>,
+
+    /// Optional running season that takes a cut of the admin fee and tracks the fish
+    #[account(
+        mut,
+        seeds = [b"season", ocean.key().as_ref(), &season.season_id.to_le_bytes()],
+        bump = season.bump
+    )]
+    pub season: Option<Account<'info, Season>>,
+
+    /// Optional leaderboard refreshed with the fish's new standing
+    #[account(
+        mut,
+        seeds = [b"leaderboard", ocean.key().as_ref()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Option<Account<'info, Leaderboard>>,
+
+    /// Optional achievements account of the fish owner, unlocked as milestones are reached
+    #[account(
+        mut,
+        seeds = [b"achievements", owner.key().as_ref()],
+        bump = achievements.bump
+    )]
+    pub achievements: Option<Account<'info, Achievements>>,
+
+    /// Optional profile of the owner, updated with the new fish
+    #[account(
+        mut,
+        seeds = [b"profile", owner.key().as_ref()],
+        bump = profile.bump
+    )]
+    pub profile: Option<Account<'info, PlayerProfile>>,
 }
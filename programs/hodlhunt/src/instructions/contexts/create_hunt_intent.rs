@@ -0,0 +1,23 @@
+use crate::state::{Fish, HuntIntent};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CreateHuntIntent<'info> {
+    #[account(constraint = hunter.owner == owner.key())]
+    pub hunter: Account<'info, Fish>,
+
+    /// Intent PDA; holds the keeper tip on top of its rent
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + HuntIntent::INIT_SPACE,
+        seeds = [b"hunt_intent", hunter.key().as_ref()],
+        bump
+    )]
+    pub intent: Account<'info, HuntIntent>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,26 @@
+use crate::state::{PlayerProfile, Referral};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CreateProfile<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + PlayerProfile::INIT_SPACE,
+        seeds = [b"profile", owner.key().as_ref()],
+        bump
+    )]
+    pub profile: Account<'info, PlayerProfile>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Optional referrer bound to the profile for good
+    #[account(
+        seeds = [b"referral", referral.referrer.as_ref()],
+        bump = referral.bump
+    )]
+    pub referral: Option<Account<'info, Referral>>,
+}
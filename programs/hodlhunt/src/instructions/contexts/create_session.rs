@@ -0,0 +1,23 @@
+use crate::state::SessionKey;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CreateSession<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + SessionKey::INIT_SPACE,
+        seeds = [b"session", owner.key().as_ref(), session_signer.key().as_ref()],
+        bump
+    )]
+    pub session: Account<'info, SessionKey>,
+
+    #[account(mut)]
+    /// CHECK: Ephemeral keypair held by the game client; receives the session budget
+    pub session_signer: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,33 @@
+use crate::state::{Fish, Ocean, TransferOffer};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CreateTransferOffer<'info> {
+    pub ocean: Account<'info, Ocean>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"fish",
+            ocean.key().as_ref(),
+            &fish.id.to_le_bytes()
+        ],
+        bump,
+        constraint = fish.owner == seller.key()
+    )]
+    pub fish: Account<'info, Fish>,
+
+    #[account(
+        init,
+        payer = seller,
+        space = 8 + TransferOffer::INIT_SPACE,
+        seeds = [b"transfer_offer", fish.key().as_ref()],
+        bump
+    )]
+    pub offer: Account<'info, TransferOffer>,
+
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
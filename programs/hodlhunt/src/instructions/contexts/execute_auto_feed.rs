@@ -0,0 +1,70 @@
+use crate::state::{Achievements, AutoFeed, Fish, Leaderboard, Ocean, PlayerProfile, Season};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ExecuteAutoFeed<'info> {
+    #[account(mut)]
+    pub ocean: Account<'info, Ocean>,
+
+    #[account(mut)]
+    pub fish: Account<'info, Fish>,
+
+    /// Escrow must belong to the fish's current owner
+    #[account(
+        mut,
+        seeds = [b"auto_feed", fish.key().as_ref(), fish.owner.as_ref()],
+        bump = auto_feed.bump,
+        has_one = fish,
+        constraint = auto_feed.owner == fish.owner
+    )]
+    pub auto_feed: Account<'info, AutoFeed>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", ocean.key().as_ref()],
+        bump = ocean.vault_bump
+    )]
+    /// CHECK: PDA vault
+    pub vault: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = admin.key() == ocean.admin
+    )]
+    /// CHECK: Admin must match ocean.admin
+    pub admin: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+
+    /// Optional running season that enters and tracks the fish
+    #[account(
+        seeds = [b"season", ocean.key().as_ref(), &season.season_id.to_le_bytes()],
+        bump = season.bump
+    )]
+    pub season: Option<Account<'info, Season>>,
+
+    /// Optional leaderboard refreshed with the fish's new standing
+    #[account(
+        mut,
+        seeds = [b"leaderboard", ocean.key().as_ref()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Option<Account<'info, Leaderboard>>,
+
+    /// Optional achievements account of the fish owner, unlocked as milestones are reached
+    #[account(
+        mut,
+        seeds = [b"achievements", fish.owner.as_ref()],
+        bump = achievements.bump
+    )]
+    pub achievements: Option<Account<'info, Achievements>>,
+
+    /// Optional profile of the fish owner, credited with the deposit
+    #[account(
+        mut,
+        seeds = [b"profile", fish.owner.as_ref()],
+        bump = profile.bump
+    )]
+    pub profile: Option<Account<'info, PlayerProfile>>,
+}
@@ -0,0 +1,102 @@
+use crate::state::{Achievements, Fish, HuntIntent, Leaderboard, Ocean, PlayerProfile, Season};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::{instructions, slot_hashes};
+
+#[derive(Accounts)]
+pub struct ExecuteHuntIntent<'info> {
+    #[account(mut)]
+    pub ocean: Account<'info, Ocean>,
+
+    #[account(mut)]
+    pub hunter: Account<'info, Fish>,
+
+    #[account(mut)]
+    pub prey: Account<'info, Fish>,
+
+    /// Intent is consumed whether or not the prey escapes; rent returns to the owner, the
+    /// tip to the keeper
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"hunt_intent", hunter.key().as_ref()],
+        bump = intent.bump,
+        has_one = hunter,
+        has_one = owner
+    )]
+    pub intent: Account<'info, HuntIntent>,
+
+    #[account(mut)]
+    /// CHECK: Intent owner; receives the intent rent
+    pub owner: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", ocean.key().as_ref()],
+        bump = ocean.vault_bump
+    )]
+    /// CHECK: PDA vault
+    pub vault: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = admin.key() == ocean.admin
+    )]
+    /// CHECK: Admin must match ocean.admin
+    pub admin: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub keeper: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    #[account(mut)]
+    /// CHECK: Prey name registry PDA; verified by derivation in the handler
+    pub prey_name_registry: AccountInfo<'info>,
+
+    #[account(address = slot_hashes::ID)]
+    /// CHECK: SlotHashes sysvar; entropy for the prey's escape roll
+    pub slot_hashes: AccountInfo<'info>,
+
+    #[account(address = instructions::ID)]
+    /// CHECK: Instructions sysvar; proves nothing runs after the escape roll
+    pub instructions: AccountInfo<'info>,
+
+    /// Optional running season that tracks the hunter's results
+    #[account(
+        seeds = [b"season", ocean.key().as_ref(), &season.season_id.to_le_bytes()],
+        bump = season.bump
+    )]
+    pub season: Option<Account<'info, Season>>,
+
+    /// Optional leaderboard refreshed with the fish's new standing
+    #[account(
+        mut,
+        seeds = [b"leaderboard", ocean.key().as_ref()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Option<Account<'info, Leaderboard>>,
+
+    /// Optional achievements account of the fish owner, unlocked as milestones are reached
+    #[account(
+        mut,
+        seeds = [b"achievements", hunter.owner.as_ref()],
+        bump = achievements.bump
+    )]
+    pub achievements: Option<Account<'info, Achievements>>,
+
+    /// Optional profile of the hunter's owner, credited with the hunt
+    #[account(
+        mut,
+        seeds = [b"profile", hunter.owner.as_ref()],
+        bump = hunter_profile.bump
+    )]
+    pub hunter_profile: Option<Account<'info, PlayerProfile>>,
+
+    /// Optional profile of the prey's owner, charged with the loss
+    #[account(
+        mut,
+        seeds = [b"profile", prey.owner.as_ref()],
+        bump = prey_profile.bump
+    )]
+    pub prey_profile: Option<Account<'info, PlayerProfile>>,
+}
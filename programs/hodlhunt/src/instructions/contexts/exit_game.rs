@@ -1,4 +1,4 @@
-use crate::state::{Fish, Ocean};
+use crate::state::{Fish, Leaderboard, Ocean, PlayerProfile};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
@@ -35,4 +35,20 @@ pub struct ExitGame<'info> {
     pub name_registry: AccountInfo<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// Optional leaderboard refreshed with the fish's new standing
+    #[account(
+        mut,
+        seeds = [b"leaderboard", ocean.key().as_ref()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Option<Account<'info, Leaderboard>>,
+
+    /// Optional profile of the owner, credited with the withdrawal
+    #[account(
+        mut,
+        seeds = [b"profile", owner.key().as_ref()],
+        bump = profile.bump
+    )]
+    pub profile: Option<Account<'info, PlayerProfile>>,
 }
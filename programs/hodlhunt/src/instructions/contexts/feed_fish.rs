@@ -1,4 +1,4 @@
-use crate::state::{Fish, Ocean};
+use crate::state::{Achievements, Delegation, Fish, Leaderboard, Ocean, PlayerProfile, Referral, Season, SessionKey};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
@@ -6,10 +6,7 @@ pub struct FeedFish<'info> {
     #[account(mut)]
     pub ocean: Account<'info, Ocean>,
 
-    #[account(
-        mut,
-        constraint = fish.owner == owner.key()
-    )]
+    #[account(mut)]
     pub fish: Account<'info, Fish>,
 
     #[account(
@@ -31,4 +28,61 @@ pub struct FeedFish<'info> {
     pub admin: AccountInfo<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// Optional grant letting a delegate sign instead of the owner
+    #[account(
+        mut,
+        seeds = [b"delegation", fish.owner.as_ref(), owner.key().as_ref()],
+        bump = delegation.bump
+    )]
+    pub delegation: Option<Account<'info, Delegation>>,
+
+    /// Optional session letting an ephemeral client key sign instead of the owner
+    #[account(
+        mut,
+        seeds = [b"session", fish.owner.as_ref(), owner.key().as_ref()],
+        bump = session.bump
+    )]
+    pub session: Option<Account<'info, SessionKey>>,
+
+    /// Optional referrer account credited with a cut of the admin fee; must be the
+    /// referrer bound to the supplied profile
+    #[account(
+        mut,
+        seeds = [b"referral", referral.referrer.as_ref()],
+        bump = referral.bump
+    )]
+    pub referral: Option<Account<'info, Referral>>,
+
+    /// Optional running season that takes a cut of the admin fee and tracks the fish
+    #[account(
+        mut,
+        seeds = [b"season", ocean.key().as_ref(), &season.season_id.to_le_bytes()],
+        bump = season.bump
+    )]
+    pub season: Option<Account<'info, Season>>,
+
+    /// Optional leaderboard refreshed with the fish's new standing
+    #[account(
+        mut,
+        seeds = [b"leaderboard", ocean.key().as_ref()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Option<Account<'info, Leaderboard>>,
+
+    /// Optional achievements account of the fish owner, unlocked as milestones are reached
+    #[account(
+        mut,
+        seeds = [b"achievements", fish.owner.as_ref()],
+        bump = achievements.bump
+    )]
+    pub achievements: Option<Account<'info, Achievements>>,
+
+    /// Optional profile of the fish owner, credited with the deposit
+    #[account(
+        mut,
+        seeds = [b"profile", fish.owner.as_ref()],
+        bump = profile.bump
+    )]
+    pub profile: Option<Account<'info, PlayerProfile>>,
 }
@@ -0,0 +1,60 @@
+use crate::state::{Achievements, Leaderboard, Ocean, PlayerProfile, Season};
+use anchor_lang::prelude::*;
+
+/// Fish to feed are passed as writable remaining accounts, one per amount.
+#[derive(Accounts)]
+pub struct FeedMany<'info> {
+    #[account(mut)]
+    pub ocean: Account<'info, Ocean>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", ocean.key().as_ref()],
+        bump = ocean.vault_bump
+    )]
+    /// CHECK: PDA vault
+    pub vault: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = admin.key() == ocean.admin
+    )]
+    /// CHECK: Admin must match ocean.admin
+    pub admin: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Optional profile of the owner, credited with the deposits
+    #[account(
+        mut,
+        seeds = [b"profile", owner.key().as_ref()],
+        bump = profile.bump
+    )]
+    pub profile: Option<Account<'info, PlayerProfile>>,
+
+    /// Optional running season that enters and tracks every fed fish
+    #[account(
+        seeds = [b"season", ocean.key().as_ref(), &season.season_id.to_le_bytes()],
+        bump = season.bump
+    )]
+    pub season: Option<Account<'info, Season>>,
+
+    /// Optional leaderboard refreshed with the standing of every fed fish
+    #[account(
+        mut,
+        seeds = [b"leaderboard", ocean.key().as_ref()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Option<Account<'info, Leaderboard>>,
+
+    /// Optional achievements account of the owner, unlocked as milestones are reached
+    #[account(
+        mut,
+        seeds = [b"achievements", owner.key().as_ref()],
+        bump = achievements.bump
+    )]
+    pub achievements: Option<Account<'info, Achievements>>,
+}
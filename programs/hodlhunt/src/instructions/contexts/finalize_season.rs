@@ -0,0 +1,15 @@
+use crate::state::{Ocean, Season};
+use anchor_lang::prelude::*;
+
+/// Candidate fish are passed as remaining accounts.
+#[derive(Accounts)]
+pub struct FinalizeSeason<'info> {
+    pub ocean: Account<'info, Ocean>,
+
+    #[account(
+        mut,
+        seeds = [b"season", ocean.key().as_ref(), &season.season_id.to_le_bytes()],
+        bump = season.bump
+    )]
+    pub season: Account<'info, Season>,
+}
@@ -0,0 +1,23 @@
+use crate::state::{AutoFeed, Fish};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct FundAutoFeed<'info> {
+    #[account(constraint = fish.owner == owner.key())]
+    pub fish: Account<'info, Fish>,
+
+    /// Escrow PDA; holds the feeding budget on top of its rent
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + AutoFeed::INIT_SPACE,
+        seeds = [b"auto_feed", fish.key().as_ref(), owner.key().as_ref()],
+        bump
+    )]
+    pub auto_feed: Account<'info, AutoFeed>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,13 @@
+use crate::state::{Leaderboard, Ocean};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct GetLeaderboard<'info> {
+    pub ocean: Account<'info, Ocean>,
+
+    #[account(
+        seeds = [b"leaderboard", ocean.key().as_ref()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Account<'info, Leaderboard>,
+}
@@ -0,0 +1,7 @@
+use crate::state::Ocean;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct GetOceanSnapshot<'info> {
+    pub ocean: Account<'info, Ocean>,
+}
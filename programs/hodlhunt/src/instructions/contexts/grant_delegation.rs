@@ -0,0 +1,20 @@
+use crate::state::Delegation;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(delegate: Pubkey)]
+pub struct GrantDelegation<'info> {
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + Delegation::INIT_SPACE,
+        seeds = [b"delegation", owner.key().as_ref(), delegate.as_ref()],
+        bump
+    )]
+    pub delegation: Account<'info, Delegation>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
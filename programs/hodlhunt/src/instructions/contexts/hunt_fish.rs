@@ -1,5 +1,6 @@
-use crate::state::{Fish, Ocean};
+use crate::state::{Achievements, Delegation, Fish, Leaderboard, Ocean, PlayerProfile, Season, SessionKey};
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::{instructions, slot_hashes};
 
 #[derive(Accounts)]
 pub struct HuntFish<'info> {
@@ -35,4 +36,67 @@ pub struct HuntFish<'info> {
     #[account(mut)]
     /// CHECK: PDA реестра имени жертвы; проверяется по деривации в обработчике
     pub prey_name_registry: AccountInfo<'info>,
+
+    #[account(address = slot_hashes::ID)]
+    /// CHECK: SlotHashes sysvar; entropy for the prey's escape roll
+    pub slot_hashes: AccountInfo<'info>,
+
+    #[account(address = instructions::ID)]
+    /// CHECK: Instructions sysvar; proves nothing runs after the escape roll
+    pub instructions: AccountInfo<'info>,
+
+    /// Optional grant letting a delegate sign instead of the owner
+    #[account(
+        mut,
+        seeds = [b"delegation", hunter.owner.as_ref(), hunter_owner.key().as_ref()],
+        bump = delegation.bump
+    )]
+    pub delegation: Option<Account<'info, Delegation>>,
+
+    /// Optional session letting an ephemeral client key sign instead of the owner
+    #[account(
+        mut,
+        seeds = [b"session", hunter.owner.as_ref(), hunter_owner.key().as_ref()],
+        bump = session.bump
+    )]
+    pub session: Option<Account<'info, SessionKey>>,
+
+    /// Optional running season that tracks the hunter's results
+    #[account(
+        seeds = [b"season", ocean.key().as_ref(), &season.season_id.to_le_bytes()],
+        bump = season.bump
+    )]
+    pub season: Option<Account<'info, Season>>,
+
+    /// Optional leaderboard refreshed with the fish's new standing
+    #[account(
+        mut,
+        seeds = [b"leaderboard", ocean.key().as_ref()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Option<Account<'info, Leaderboard>>,
+
+    /// Optional achievements account of the fish owner, unlocked as milestones are reached
+    #[account(
+        mut,
+        seeds = [b"achievements", hunter.owner.as_ref()],
+        bump = achievements.bump
+    )]
+    pub achievements: Option<Account<'info, Achievements>>,
+
+    /// Optional profile of the hunter's owner, credited with the hunt
+    #[account(
+        mut,
+        seeds = [b"profile", hunter.owner.as_ref()],
+        bump = hunter_profile.bump
+    )]
+    pub hunter_profile: Option<Account<'info, PlayerProfile>>,
+
+    /// Optional profile of the prey's owner, charged with the loss
+    #[account(
+        mut,
+        seeds = [b"profile", prey.owner.as_ref()],
+        bump = prey_profile.bump
+    )]
+    pub prey_profile: Option<Account<'info, PlayerProfile>>,
 }
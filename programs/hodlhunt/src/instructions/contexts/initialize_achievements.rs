@@ -0,0 +1,19 @@
+use crate::state::Achievements;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct InitializeAchievements<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + Achievements::INIT_SPACE,
+        seeds = [b"achievements", owner.key().as_ref()],
+        bump
+    )]
+    pub achievements: Account<'info, Achievements>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
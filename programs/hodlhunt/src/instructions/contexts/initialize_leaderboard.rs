@@ -0,0 +1,21 @@
+use crate::state::{Leaderboard, Ocean};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct InitializeLeaderboard<'info> {
+    pub ocean: Account<'info, Ocean>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Leaderboard::INIT_SPACE,
+        seeds = [b"leaderboard", ocean.key().as_ref()],
+        bump
+    )]
+    pub leaderboard: Account<'info, Leaderboard>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
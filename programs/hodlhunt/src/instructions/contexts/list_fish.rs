@@ -0,0 +1,33 @@
+use crate::state::{Fish, Listing, Ocean};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ListFish<'info> {
+    pub ocean: Account<'info, Ocean>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"fish",
+            ocean.key().as_ref(),
+            &fish.id.to_le_bytes()
+        ],
+        bump,
+        constraint = fish.owner == seller.key()
+    )]
+    pub fish: Account<'info, Fish>,
+
+    #[account(
+        init,
+        payer = seller,
+        space = 8 + Listing::INIT_SPACE,
+        seeds = [b"listing", fish.key().as_ref()],
+        bump
+    )]
+    pub listing: Account<'info, Listing>,
+
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
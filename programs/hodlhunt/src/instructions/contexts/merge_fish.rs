@@ -0,0 +1,74 @@
+use crate::state::{Fish, Leaderboard, Ocean, PlayerProfile};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct MergeFish<'info> {
+    #[account(mut)]
+    pub ocean: Account<'info, Ocean>,
+
+    /// Fish that absorbs the share of `source`
+    #[account(
+        mut,
+        seeds = [
+            b"fish",
+            ocean.key().as_ref(),
+            &target.id.to_le_bytes()
+        ],
+        bump,
+        constraint = target.owner == owner.key()
+    )]
+    pub target: Account<'info, Fish>,
+
+    /// Fish that is absorbed and closed back to the owner
+    #[account(
+        mut,
+        close = owner,
+        seeds = [
+            b"fish",
+            ocean.key().as_ref(),
+            &source.id.to_le_bytes()
+        ],
+        bump,
+        constraint = source.owner == owner.key()
+    )]
+    pub source: Account<'info, Fish>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", ocean.key().as_ref()],
+        bump = ocean.vault_bump
+    )]
+    /// CHECK: PDA vault
+    pub vault: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = admin.key() == ocean.admin
+    )]
+    /// CHECK: Admin must match ocean.admin
+    pub admin: AccountInfo<'info>,
+
+    #[account(mut)]
+    /// CHECK: Name registry PDA of the absorbed fish; verified by derivation in the handler
+    pub source_name_registry: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Optional profile of the owner, dropping the absorbed fish
+    #[account(
+        mut,
+        seeds = [b"profile", owner.key().as_ref()],
+        bump = profile.bump
+    )]
+    pub profile: Option<Account<'info, PlayerProfile>>,
+    /// Optional leaderboard refreshed with the merged fish's new standing
+    #[account(
+        mut,
+        seeds = [b"leaderboard", ocean.key().as_ref()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Option<Account<'info, Leaderboard>>,
+}
@@ -0,0 +1,30 @@
+use crate::state::{Fish, Ocean};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(fish_id: u64)]
+pub struct MigrateFish<'info> {
+    pub ocean: Account<'info, Ocean>,
+
+    #[account(mut)]
+    /// CHECK: Legacy fish PDA keyed by owner; seeds and layout are verified in the handler
+    pub legacy_fish: AccountInfo<'info>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + Fish::INIT_SPACE,
+        seeds = [
+            b"fish",
+            ocean.key().as_ref(),
+            &fish_id.to_le_bytes()
+        ],
+        bump
+    )]
+    pub fish: Account<'info, Fish>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -1,25 +1,125 @@
+pub mod accept_bid;
+pub mod accept_transfer_offer;
+pub mod buy_listed_fish;
+pub mod buy_shield;
+pub mod cancel_bid;
+pub mod cancel_hunt_intent;
+pub mod cancel_listing;
+pub mod cancel_transfer_offer;
+pub mod check_hunt;
+pub mod claim_referral_earnings;
+pub mod claim_season_prize;
+pub mod close_auto_feed;
+pub mod close_listing;
+pub mod close_season;
 pub mod create_fish;
+pub mod create_hunt_intent;
+pub mod create_profile;
+pub mod create_session;
+pub mod create_transfer_offer;
+pub mod execute_auto_feed;
+pub mod execute_hunt_intent;
 pub mod exit_game;
 pub mod feed_fish;
+pub mod feed_many;
+pub mod finalize_season;
+pub mod fund_auto_feed;
 pub mod get_fish_info;
+pub mod get_fish_snapshot;
+pub mod get_leaderboard;
 pub mod get_new_share;
+pub mod get_ocean_snapshot;
 pub mod get_share_value;
+pub mod grant_delegation;
 pub mod hunt_fish;
+pub mod initialize_achievements;
+pub mod initialize_leaderboard;
 pub mod initialize_ocean;
+pub mod list_fish;
+pub mod merge_fish;
+pub mod migrate_fish;
+pub mod open_season;
+pub mod place_bid;
 pub mod place_hunting_mark;
+pub mod prune_leaderboard;
+pub mod quote_create;
+pub mod quote_exit;
+pub mod quote_feed;
+pub mod quote_hunt;
+pub mod quote_mark;
+pub mod quote_shield;
+pub mod reap_starved_fish;
+pub mod register_referrer;
 pub mod resurrect_fish;
+pub mod revoke_delegation;
+pub mod revoke_session;
+pub mod set_profile_handle;
+pub mod settle_auction;
+pub mod split_fish;
+pub mod sync_profile;
 pub mod transfer_fish;
 pub mod update_ocean_daily;
+pub mod withdraw_auto_feed;
 
+pub use accept_bid::AcceptBid;
+pub use accept_transfer_offer::AcceptTransferOffer;
+pub use buy_listed_fish::BuyListedFish;
+pub use buy_shield::BuyShield;
+pub use cancel_bid::CancelBid;
+pub use cancel_hunt_intent::CancelHuntIntent;
+pub use cancel_listing::CancelListing;
+pub use cancel_transfer_offer::CancelTransferOffer;
+pub use check_hunt::CheckHunt;
+pub use claim_referral_earnings::ClaimReferralEarnings;
+pub use claim_season_prize::ClaimSeasonPrize;
+pub use close_auto_feed::CloseAutoFeed;
+pub use close_listing::CloseListing;
+pub use close_season::CloseSeason;
 pub use create_fish::CreateFish;
+pub use create_hunt_intent::CreateHuntIntent;
+pub use create_profile::CreateProfile;
+pub use create_session::CreateSession;
+pub use create_transfer_offer::CreateTransferOffer;
+pub use execute_auto_feed::ExecuteAutoFeed;
+pub use execute_hunt_intent::ExecuteHuntIntent;
 pub use exit_game::ExitGame;
 pub use feed_fish::FeedFish;
+pub use feed_many::FeedMany;
+pub use finalize_season::FinalizeSeason;
+pub use fund_auto_feed::FundAutoFeed;
 pub use get_fish_info::GetFishInfo;
+pub use get_fish_snapshot::GetFishSnapshot;
+pub use get_leaderboard::GetLeaderboard;
 pub use get_new_share::GetNewShare;
+pub use get_ocean_snapshot::GetOceanSnapshot;
 pub use get_share_value::GetShareValue;
+pub use grant_delegation::GrantDelegation;
 pub use hunt_fish::HuntFish;
+pub use initialize_achievements::InitializeAchievements;
+pub use initialize_leaderboard::InitializeLeaderboard;
 pub use initialize_ocean::InitializeOcean;
+pub use list_fish::ListFish;
+pub use merge_fish::MergeFish;
+pub use migrate_fish::MigrateFish;
+pub use open_season::OpenSeason;
+pub use place_bid::PlaceBid;
 pub use place_hunting_mark::PlaceHuntingMark;
+pub use prune_leaderboard::PruneLeaderboard;
+pub use quote_create::QuoteCreate;
+pub use quote_exit::QuoteExit;
+pub use quote_feed::QuoteFeed;
+pub use quote_hunt::QuoteHunt;
+pub use quote_mark::QuoteMark;
+pub use quote_shield::QuoteShield;
+pub use reap_starved_fish::ReapStarvedFish;
+pub use register_referrer::RegisterReferrer;
 pub use resurrect_fish::ResurrectFish;
+pub use revoke_delegation::RevokeDelegation;
+pub use revoke_session::RevokeSession;
+pub use set_profile_handle::SetProfileHandle;
+pub use settle_auction::SettleAuction;
+pub use split_fish::SplitFish;
+pub use sync_profile::SyncProfile;
 pub use transfer_fish::TransferFish;
 pub use update_ocean_daily::UpdateOceanDaily;
+pub use withdraw_auto_feed::WithdrawAutoFeed;
@@ -0,0 +1,34 @@
+use crate::state::{Ocean, Season, SeasonSchedule};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(season_id: u64)]
+pub struct OpenSeason<'info> {
+    pub ocean: Account<'info, Ocean>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + Season::INIT_SPACE,
+        seeds = [b"season", ocean.key().as_ref(), &season_id.to_le_bytes()],
+        bump
+    )]
+    pub season: Account<'info, Season>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + SeasonSchedule::INIT_SPACE,
+        seeds = [b"season_schedule", ocean.key().as_ref()],
+        bump
+    )]
+    pub schedule: Account<'info, SeasonSchedule>,
+
+    #[account(
+        mut,
+        constraint = admin.key() == ocean.admin
+    )]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,30 @@
+use crate::state::{Bid, Fish, Listing};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct PlaceBid<'info> {
+    pub fish: Account<'info, Fish>,
+
+    #[account(
+        mut,
+        seeds = [b"listing", fish.key().as_ref()],
+        bump = listing.bump,
+        has_one = fish
+    )]
+    pub listing: Account<'info, Listing>,
+
+    /// Bid escrow PDA; holds the bid amount on top of its rent
+    #[account(
+        init_if_needed,
+        payer = bidder,
+        space = 8 + Bid::INIT_SPACE,
+        seeds = [b"bid", listing.key().as_ref(), bidder.key().as_ref()],
+        bump
+    )]
+    pub bid: Account<'info, Bid>,
+
+    #[account(mut)]
+    pub bidder: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
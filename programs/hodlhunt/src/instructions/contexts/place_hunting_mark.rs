@@ -1,4 +1,4 @@
-use crate::state::{Fish, Ocean};
+use crate::state::{Delegation, Fish, Ocean, SessionKey};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
@@ -31,4 +31,20 @@ pub struct PlaceHuntingMark<'info> {
     pub admin: AccountInfo<'info>,
 
     pub system_program: Program<'info, System>,
+
+    /// Optional grant letting a delegate sign instead of the owner
+    #[account(
+        mut,
+        seeds = [b"delegation", hunter.owner.as_ref(), hunter_owner.key().as_ref()],
+        bump = delegation.bump
+    )]
+    pub delegation: Option<Account<'info, Delegation>>,
+
+    /// Optional session letting an ephemeral client key sign instead of the owner
+    #[account(
+        mut,
+        seeds = [b"session", hunter.owner.as_ref(), hunter_owner.key().as_ref()],
+        bump = session.bump
+    )]
+    pub session: Option<Account<'info, SessionKey>>,
 }
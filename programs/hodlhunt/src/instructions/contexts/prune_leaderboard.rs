@@ -0,0 +1,15 @@
+use crate::state::{Leaderboard, Ocean};
+use anchor_lang::prelude::*;
+
+/// Fish accounts, living, dead or closed, are passed as remaining accounts.
+#[derive(Accounts)]
+pub struct PruneLeaderboard<'info> {
+    pub ocean: Account<'info, Ocean>,
+
+    #[account(
+        mut,
+        seeds = [b"leaderboard", ocean.key().as_ref()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Account<'info, Leaderboard>,
+}
@@ -0,0 +1,8 @@
+use crate::state::{Fish, Ocean};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct QuoteExit<'info> {
+    pub ocean: Account<'info, Ocean>,
+    pub fish: Account<'info, Fish>,
+}
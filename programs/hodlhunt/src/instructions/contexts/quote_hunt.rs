@@ -0,0 +1,9 @@
+use crate::state::{Fish, Ocean};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct QuoteHunt<'info> {
+    pub ocean: Account<'info, Ocean>,
+    pub hunter: Account<'info, Fish>,
+    pub prey: Account<'info, Fish>,
+}
@@ -0,0 +1,60 @@
+use crate::state::{Fish, Leaderboard, Listing, Ocean, PlayerProfile};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ReapStarvedFish<'info> {
+    #[account(mut)]
+    pub ocean: Account<'info, Ocean>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"fish",
+            ocean.key().as_ref(),
+            &fish.id.to_le_bytes()
+        ],
+        bump
+    )]
+    pub fish: Account<'info, Fish>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", ocean.key().as_ref()],
+        bump = ocean.vault_bump
+    )]
+    /// CHECK: PDA vault
+    pub vault: AccountInfo<'info>,
+
+    /// Anyone may reap a starving fish and collect the bounty
+    #[account(mut)]
+    pub reaper: Signer<'info>,
+
+    #[account(mut)]
+    /// CHECK: Name registry PDA of the starved fish; verified by derivation in the handler
+    pub name_registry: AccountInfo<'info>,
+
+    /// Optional profile of the fish owner, charged with the loss
+    #[account(
+        mut,
+        seeds = [b"profile", fish.owner.as_ref()],
+        bump = profile.bump
+    )]
+    pub profile: Option<Account<'info, PlayerProfile>>,
+
+    /// Optional leaderboard refreshed with the starved fish dropped
+    #[account(
+        mut,
+        seeds = [b"leaderboard", ocean.key().as_ref()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Option<Account<'info, Leaderboard>>,
+    /// Optional listing of the fish, deactivated with the bids passed as `(bid, bidder)`
+    /// pairs in remaining accounts refunded
+    #[account(
+        mut,
+        seeds = [b"listing", fish.key().as_ref()],
+        bump = listing.bump,
+        has_one = fish
+    )]
+    pub listing: Option<Account<'info, Listing>>,
+}
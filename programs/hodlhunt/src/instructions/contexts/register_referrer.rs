@@ -0,0 +1,19 @@
+use crate::state::Referral;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RegisterReferrer<'info> {
+    #[account(
+        init,
+        payer = referrer,
+        space = 8 + Referral::INIT_SPACE,
+        seeds = [b"referral", referrer.key().as_ref()],
+        bump
+    )]
+    pub referral: Account<'info, Referral>,
+
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
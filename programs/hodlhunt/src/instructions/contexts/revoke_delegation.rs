@@ -0,0 +1,17 @@
+use crate::state::Delegation;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RevokeDelegation<'info> {
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"delegation", owner.key().as_ref(), delegation.delegate.as_ref()],
+        bump = delegation.bump,
+        has_one = owner
+    )]
+    pub delegation: Account<'info, Delegation>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
@@ -0,0 +1,26 @@
+use crate::state::SessionKey;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct RevokeSession<'info> {
+    #[account(
+        mut,
+        close = owner,
+        seeds = [b"session", owner.key().as_ref(), session.session_key.as_ref()],
+        bump = session.bump,
+        has_one = owner
+    )]
+    pub session: Account<'info, SessionKey>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Optional session key co-signing so its unspent budget is swept back to the owner
+    #[account(
+        mut,
+        address = session.session_key
+    )]
+    pub session_signer: Option<Signer<'info>>,
+}
@@ -0,0 +1,15 @@
+use crate::state::PlayerProfile;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetProfileHandle<'info> {
+    #[account(
+        mut,
+        seeds = [b"profile", owner.key().as_ref()],
+        bump = profile.bump,
+        has_one = owner
+    )]
+    pub profile: Account<'info, PlayerProfile>,
+
+    pub owner: Signer<'info>,
+}
@@ -0,0 +1,76 @@
+use crate::state::{Bid, Fish, Leaderboard, Listing, Ocean, PlayerProfile};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SettleAuction<'info> {
+    pub ocean: Account<'info, Ocean>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"fish",
+            ocean.key().as_ref(),
+            &fish.id.to_le_bytes()
+        ],
+        bump
+    )]
+    pub fish: Account<'info, Fish>,
+
+    #[account(
+        mut,
+        seeds = [b"listing", fish.key().as_ref()],
+        bump = listing.bump,
+        has_one = fish,
+        has_one = seller
+    )]
+    pub listing: Account<'info, Listing>,
+
+    /// Winning bid; its escrow pays the sale and its rent returns to the bidder
+    #[account(
+        mut,
+        close = bidder,
+        seeds = [b"bid", listing.key().as_ref(), bidder.key().as_ref()],
+        bump = bid.bump,
+        has_one = listing,
+        has_one = bidder
+    )]
+    pub bid: Account<'info, Bid>,
+
+    #[account(mut)]
+    /// CHECK: Seller recorded in the listing; receives the sale proceeds
+    pub seller: AccountInfo<'info>,
+
+    #[account(mut)]
+    /// CHECK: Bidder recorded in the bid; becomes the new fish owner
+    pub bidder: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = admin.key() == ocean.admin
+    )]
+    /// CHECK: Admin must match ocean.admin
+    pub admin: AccountInfo<'info>,
+
+    /// Optional profile of the seller
+    #[account(
+        mut,
+        seeds = [b"profile", seller.key().as_ref()],
+        bump = seller_profile.bump
+    )]
+    pub seller_profile: Option<Account<'info, PlayerProfile>>,
+
+    /// Optional profile of the winning bidder
+    #[account(
+        mut,
+        seeds = [b"profile", bidder.key().as_ref()],
+        bump = bidder_profile.bump
+    )]
+    pub bidder_profile: Option<Account<'info, PlayerProfile>>,
+    /// Optional leaderboard refreshed with the fish's new owner
+    #[account(
+        mut,
+        seeds = [b"leaderboard", ocean.key().as_ref()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Option<Account<'info, Leaderboard>>,
+}
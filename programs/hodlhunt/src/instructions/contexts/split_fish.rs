@@ -0,0 +1,73 @@
+use crate::state::{Fish, Leaderboard, Ocean, PlayerProfile};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(name: String, split_bps: u64)]
+pub struct SplitFish<'info> {
+    #[account(mut)]
+    pub ocean: Account<'info, Ocean>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"fish",
+            ocean.key().as_ref(),
+            &parent.id.to_le_bytes()
+        ],
+        bump,
+        constraint = parent.owner == owner.key()
+    )]
+    pub parent: Account<'info, Fish>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + Fish::INIT_SPACE,
+        seeds = [
+            b"fish",
+            ocean.key().as_ref(),
+            &ocean.next_fish_id.to_le_bytes()
+        ],
+        bump
+    )]
+    pub child: Account<'info, Fish>,
+
+    /// CHECK: Name registry PDA (unique per name)
+    #[account(mut)]
+    pub name_registry: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"vault", ocean.key().as_ref()],
+        bump = ocean.vault_bump
+    )]
+    /// CHECK: PDA vault
+    pub vault: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = admin.key() == ocean.admin
+    )]
+    /// CHECK: Admin must match ocean.admin
+    pub admin: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// Optional profile of the owner, updated with the child fish
+    #[account(
+        mut,
+        seeds = [b"profile", owner.key().as_ref()],
+        bump = profile.bump
+    )]
+    pub profile: Option<Account<'info, PlayerProfile>>,
+    /// Optional leaderboard refreshed with the parent's and child's new standings
+    #[account(
+        mut,
+        seeds = [b"leaderboard", ocean.key().as_ref()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Option<Account<'info, Leaderboard>>,
+}
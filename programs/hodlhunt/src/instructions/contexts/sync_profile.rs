@@ -0,0 +1,18 @@
+use crate::state::{Ocean, PlayerProfile};
+use anchor_lang::prelude::*;
+
+/// The owner's fish are passed as remaining accounts.
+#[derive(Accounts)]
+pub struct SyncProfile<'info> {
+    pub ocean: Account<'info, Ocean>,
+
+    #[account(
+        mut,
+        seeds = [b"profile", owner.key().as_ref()],
+        bump = profile.bump,
+        has_one = owner
+    )]
+    pub profile: Account<'info, PlayerProfile>,
+
+    pub owner: Signer<'info>,
+}
@@ -1,38 +1,49 @@
-use crate::state::{Fish, Ocean};
+use crate::state::{Fish, Leaderboard, Ocean, PlayerProfile};
 use anchor_lang::prelude::*;
 
 #[derive(Accounts)]
 pub struct TransferFish<'info> {
-    /// Ocean state (not modified, but client sends it first; keep mut to be flexible)
-    #[account(mut)]
     pub ocean: Account<'info, Ocean>,
 
-    /// The original fish account to transfer from (will be closed to current_owner)
-    #[account(mut, close = current_owner)]
-    pub fish: Account<'info, Fish>,
-
-    /// New fish account initialized for the new owner with the same fish id
+    /// Fish being transferred; its address is keyed by ocean and id so it stays put
     #[account(
-        init,
-        payer = current_owner,
-        space = 8 + Fish::INIT_SPACE,
+        mut,
         seeds = [
             b"fish",
-            new_owner.key().as_ref(),
+            ocean.key().as_ref(),
             &fish.id.to_le_bytes()
         ],
         bump
     )]
-    pub new_fish: Account<'info, Fish>,
+    pub fish: Account<'info, Fish>,
 
-    /// Current owner who authorizes the transfer and receives rent from the closed account
-    #[account(mut)]
+    /// Current owner who authorizes the transfer
     pub current_owner: Signer<'info>,
 
     /// New owner who will receive the fish (no signature required)
     /// CHECK: not a signer by design; validated in instruction logic
-    #[account(mut)]
     pub new_owner: AccountInfo<'info>,
 
-    pub system_program: Program<'info, System>,
+    /// Optional profile of the current owner
+    #[account(
+        mut,
+        seeds = [b"profile", current_owner.key().as_ref()],
+        bump = from_profile.bump
+    )]
+    pub from_profile: Option<Account<'info, PlayerProfile>>,
+
+    /// Optional profile of the new owner, updated only when the new owner signs
+    #[account(
+        mut,
+        seeds = [b"profile", new_owner.key().as_ref()],
+        bump = to_profile.bump
+    )]
+    pub to_profile: Option<Account<'info, PlayerProfile>>,
+    /// Optional leaderboard refreshed with the fish's new owner
+    #[account(
+        mut,
+        seeds = [b"leaderboard", ocean.key().as_ref()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Option<Account<'info, Leaderboard>>,
 }
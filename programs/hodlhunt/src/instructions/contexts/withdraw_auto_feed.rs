@@ -0,0 +1,16 @@
+use crate::state::AutoFeed;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct WithdrawAutoFeed<'info> {
+    #[account(
+        mut,
+        seeds = [b"auto_feed", auto_feed.fish.as_ref(), owner.key().as_ref()],
+        bump = auto_feed.bump,
+        has_one = owner
+    )]
+    pub auto_feed: Account<'info, AutoFeed>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
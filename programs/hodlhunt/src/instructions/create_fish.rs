@@ -1,5 +1,6 @@
 use super::common::{
     apply_creation_finance, init_new_fish_meta, mint_fish_share, reserve_name_registry,
+    track_season, unlock_achievements, AdminFeeCuts,
 };
 use crate::{events::*, instructions::CreateFish};
 use anchor_lang::prelude::*;
@@ -18,12 +19,51 @@ pub fn handle(ctx: Context<CreateFish>, name: String, deposit: u64) -> Result<()
 
     reserve_name_registry(owner, &ctx.accounts.name_registry, system_program, trimmed)?;
 
-    let (_admin_fee, _pool_fee, _value) =
-        apply_creation_finance(owner, vault, admin, system_program, deposit)?;
+    let bound_referrer = ctx
+        .accounts
+        .profile
+        .as_ref()
+        .map(|profile| profile.referrer);
+    let (_admin_fee, _pool_fee, _value) = apply_creation_finance(
+        owner,
+        vault,
+        admin,
+        system_program,
+        deposit,
+        0,
+        AdminFeeCuts {
+            referral: ctx.accounts.referral.as_mut(),
+            bound_referrer,
+            season: ctx.accounts.season.as_mut(),
+        },
+    )?;
     ocean.balance_fishes = ocean.balance_fishes.saturating_add(_pool_fee);
 
     let share = mint_fish_share(ocean, fish, _value);
     init_new_fish_meta(ocean, fish, owner.key(), trimmed);
+    let created_at = fish.created_at;
+    track_season(
+        ctx.accounts.season.as_ref(),
+        ocean,
+        fish,
+        0,
+        _value,
+        created_at,
+    );
+    if let Some(leaderboard) = ctx.accounts.leaderboard.as_mut() {
+        leaderboard.record(fish, created_at);
+    }
+    unlock_achievements(
+        ctx.accounts.achievements.as_mut(),
+        ocean,
+        fish,
+        0,
+        created_at,
+    );
+    if let Some(profile) = ctx.accounts.profile.as_mut() {
+        profile.add_fish(fish.id)?;
+        profile.record_deposit(_value);
+    }
 
     emit!(FishCreated {
         fish_id: fish.id,
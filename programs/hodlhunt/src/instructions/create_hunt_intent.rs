@@ -0,0 +1,70 @@
+use crate::constants::fees;
+use crate::errors::ErrorCode;
+use crate::{events::*, instructions::CreateHuntIntent, utils::*};
+use anchor_lang::prelude::*;
+
+/// Posts a hunt intent for the hunter fish and escrows the keeper tip. Either `prey_id`
+/// names the target, with `max_slippage_bps` bounding its share, or `max_prey_share`
+/// bounds which prey a keeper may pick, in which case the slippage must be zero.
+pub fn handle(
+    ctx: Context<CreateHuntIntent>,
+    prey_id: u64,
+    expected_prey_share: u64,
+    max_prey_share: u64,
+    max_slippage_bps: u64,
+    deadline: i64,
+    tip: u64,
+) -> Result<()> {
+    let hunter = &ctx.accounts.hunter;
+    let intent = &mut ctx.accounts.intent;
+    let owner = &ctx.accounts.owner;
+    let system_program = &ctx.accounts.system_program;
+
+    let now = Clock::get()?.unix_timestamp;
+
+    hunter.ensure_alive()?;
+    hunter.ensure_not_trade_locked()?;
+    require!(
+        prey_id != hunter.id && (prey_id != 0 || max_prey_share > 0),
+        ErrorCode::InvalidIntent
+    );
+    // Slippage only bounds a named prey; a predicate intent is bounded by `max_prey_share`.
+    require!(
+        max_slippage_bps <= fees::BASIS_POINTS_DIVISOR && (prey_id != 0 || max_slippage_bps == 0),
+        ErrorCode::InvalidIntent
+    );
+    require!(deadline > now, ErrorCode::InvalidExpiry);
+
+    require!(owner.lamports() >= tip, ErrorCode::InsufficientFunds);
+    transfer_from_signer(
+        &owner.to_account_info(),
+        &intent.to_account_info(),
+        &system_program.to_account_info(),
+        tip,
+    )?;
+
+    intent.hunter = hunter.key();
+    intent.hunter_id = hunter.id;
+    intent.owner = owner.key();
+    intent.prey_id = prey_id;
+    intent.expected_prey_share = expected_prey_share;
+    intent.max_prey_share = max_prey_share;
+    intent.max_slippage_bps = max_slippage_bps;
+    intent.tip = tip;
+    intent.deadline = deadline;
+    intent.created_at = now;
+    intent.bump = ctx.bumps.intent;
+
+    emit!(HuntIntentCreated {
+        hunter_id: hunter.id,
+        owner: owner.key(),
+        prey_id,
+        expected_prey_share,
+        max_prey_share,
+        max_slippage_bps,
+        tip,
+        deadline,
+    });
+
+    Ok(())
+}
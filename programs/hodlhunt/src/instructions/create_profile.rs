@@ -0,0 +1,29 @@
+use crate::errors::ErrorCode;
+use crate::state::PlayerProfile;
+use crate::{events::*, instructions::CreateProfile};
+use anchor_lang::prelude::*;
+
+/// Opens the caller's player profile with a display handle. Fish the caller already
+/// owns are listed with `sync_profile`; later ones are tracked by the handlers. A supplied
+/// referral binds its referrer, the only one paid referral cuts on the player's fees.
+pub fn handle(ctx: Context<CreateProfile>, handle: String) -> Result<()> {
+    let profile = &mut ctx.accounts.profile;
+    let owner = &ctx.accounts.owner;
+
+    profile.owner = owner.key();
+    profile.handle = PlayerProfile::validate_handle(&handle)?.to_string();
+    if let Some(referral) = ctx.accounts.referral.as_ref() {
+        require_keys_neq!(referral.referrer, owner.key(), ErrorCode::InvalidReferral);
+        profile.referrer = referral.referrer;
+    }
+    profile.created_at = Clock::get()?.unix_timestamp;
+    profile.bump = ctx.bumps.profile;
+
+    emit!(ProfileCreated {
+        owner: owner.key(),
+        handle: profile.handle.clone(),
+        referrer: profile.referrer,
+    });
+
+    Ok(())
+}
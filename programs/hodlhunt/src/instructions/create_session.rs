@@ -0,0 +1,54 @@
+use crate::constants::session as cfg;
+use crate::errors::ErrorCode;
+use crate::{events::*, instructions::CreateSession, utils::*};
+use anchor_lang::prelude::*;
+
+/// Opens a session for an ephemeral client key and funds it with `budget` lamports from
+/// the owner. The session can feed, hunt and mark on the owner's fish for `duration`
+/// seconds, spending no more than its budget.
+pub fn handle(ctx: Context<CreateSession>, duration: i64, budget: u64) -> Result<()> {
+    let session = &mut ctx.accounts.session;
+    let session_signer = &ctx.accounts.session_signer;
+    let owner = &ctx.accounts.owner;
+    let system_program = &ctx.accounts.system_program;
+
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(
+        session_signer.key() != owner.key(),
+        ErrorCode::InvalidSession
+    );
+    require!(
+        duration > 0 && duration <= cfg::MAX_DURATION_SECONDS,
+        ErrorCode::InvalidSession
+    );
+    require!(
+        budget <= cfg::MAX_BUDGET_LAMPORTS,
+        ErrorCode::InvalidSession
+    );
+    require!(owner.lamports() >= budget, ErrorCode::InsufficientFunds);
+
+    transfer_from_signer(
+        &owner.to_account_info(),
+        session_signer,
+        &system_program.to_account_info(),
+        budget,
+    )?;
+
+    session.owner = owner.key();
+    session.session_key = session_signer.key();
+    session.expires_at = now + duration;
+    session.budget = budget;
+    session.spent = 0;
+    session.created_at = now;
+    session.bump = ctx.bumps.session;
+
+    emit!(SessionCreated {
+        owner: owner.key(),
+        session_key: session_signer.key(),
+        expires_at: session.expires_at,
+        budget,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use crate::errors::ErrorCode;
+use crate::{events::*, instructions::CreateTransferOffer, Fish};
+use anchor_lang::prelude::*;
+
+/// Opens a transfer offer for a fish towards a specific recipient with an optional sale
+/// price and expiry. The fish is frozen from feeding, hunting and exiting until the
+/// offer is accepted or cancelled.
+pub fn handle(
+    ctx: Context<CreateTransferOffer>,
+    recipient: Pubkey,
+    price: u64,
+    expires_at: Option<i64>,
+) -> Result<()> {
+    let fish = &mut ctx.accounts.fish;
+    let offer = &mut ctx.accounts.offer;
+    let seller = &ctx.accounts.seller;
+
+    let now = Clock::get()?.unix_timestamp;
+
+    fish.ensure_alive()?;
+    fish.ensure_owned_by(&seller.key())?;
+    fish.ensure_not_trade_locked()?;
+    require!(recipient != seller.key(), ErrorCode::CannotTransferToSelf);
+
+    let expires_at = expires_at.unwrap_or(0);
+    if expires_at != 0 {
+        require!(expires_at > now, ErrorCode::InvalidExpiry);
+    }
+
+    offer.fish = fish.key();
+    offer.fish_id = fish.id;
+    offer.seller = seller.key();
+    offer.recipient = recipient;
+    offer.price = price;
+    offer.created_at = now;
+    offer.expires_at = expires_at;
+    offer.bump = ctx.bumps.offer;
+
+    fish.trade_lock = Fish::TRADE_LOCK_OFFER;
+
+    emit!(TransferOfferCreated {
+        fish_id: fish.id,
+        seller: seller.key(),
+        recipient,
+        price,
+        expires_at,
+    });
+
+    Ok(())
+}
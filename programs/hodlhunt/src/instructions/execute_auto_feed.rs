@@ -0,0 +1,80 @@
+use crate::constants::auto_feed as cfg;
+use crate::errors::ErrorCode;
+use crate::instructions::common::{apply_feeding, record_feeding, FeedingTrackers};
+use crate::state::{AutoFeed, Fish};
+use crate::{events::*, instructions::ExecuteAutoFeed, utils::*};
+use anchor_lang::prelude::*;
+
+/// Feeds a fish from its auto-feed escrow once it is within the auto-feed window of
+/// becoming hungry. Any keeper may call this; the fish is fed the current minimum
+/// amount, capped by the owner's limit, and the keeper is paid a fixed tip from the
+/// escrow. Supplied season, leaderboard and achievements accounts are updated as for
+/// `feed_fish`.
+pub fn handle(ctx: Context<ExecuteAutoFeed>) -> Result<()> {
+    let ocean = &mut ctx.accounts.ocean;
+    let fish = &mut ctx.accounts.fish;
+    let auto_feed = &mut ctx.accounts.auto_feed;
+    let vault = &ctx.accounts.vault;
+    let admin = &ctx.accounts.admin;
+    let keeper = &ctx.accounts.keeper;
+
+    fish.ensure_alive()?;
+    fish.ensure_not_trade_locked()?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let due_at = fish.last_fed_at + Fish::PREY_COOLDOWN - cfg::WINDOW_SECONDS;
+    require!(now >= due_at, ErrorCode::AutoFeedNotDue);
+
+    let feeding_amount = min_feeding_amount(ocean, fish);
+    require!(
+        auto_feed.max_feed_amount == 0 || feeding_amount <= auto_feed.max_feed_amount,
+        ErrorCode::AutoFeedCapExceeded
+    );
+
+    let quote = feed_breakdown(ocean, fish, feeding_amount, now);
+    let keeper_tip = cfg::KEEPER_TIP_LAMPORTS;
+    let escrow = auto_feed.to_account_info();
+    require!(
+        AutoFeed::available(&escrow)? >= quote.total_cost + keeper_tip,
+        ErrorCode::InsufficientEscrow
+    );
+
+    transfer_lamports(&escrow, vault, quote.feeding_amount + quote.pool_fee)?;
+    transfer_lamports(&escrow, admin, quote.admin_fee)?;
+    transfer_lamports(&escrow, &keeper.to_account_info(), keeper_tip)?;
+
+    auto_feed.total_spent = auto_feed
+        .total_spent
+        .saturating_add(quote.total_cost + keeper_tip);
+    auto_feed.executions = auto_feed.executions.saturating_add(1);
+    auto_feed.last_executed_at = now;
+
+    let value_before = share_to_value(ocean, fish.share);
+    apply_feeding(ocean, fish, &quote, now);
+    record_feeding(
+        FeedingTrackers {
+            season: ctx.accounts.season.as_ref(),
+            leaderboard: ctx.accounts.leaderboard.as_mut(),
+            achievements: ctx.accounts.achievements.as_mut(),
+        },
+        ocean,
+        fish,
+        value_before,
+        feeding_amount,
+        now,
+    );
+    if let Some(profile) = ctx.accounts.profile.as_mut() {
+        profile.record_deposit(feeding_amount);
+    }
+
+    emit!(AutoFeedExecuted {
+        fish_id: fish.id,
+        keeper: keeper.key(),
+        feeding_amount,
+        total_cost: quote.total_cost,
+        keeper_tip,
+        available: AutoFeed::available(&escrow)?,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,114 @@
+use crate::errors::ErrorCode;
+use crate::instructions::common::{
+    release_name_if_dead, resolve_failed_hunt, resolve_hunt, track_season, unlock_achievements,
+};
+use crate::{events::*, instructions::ExecuteHuntIntent, utils::*};
+use anchor_lang::prelude::*;
+
+/// Executes a hunter's intent on behalf of its owner. The keeper supplies the prey; the
+/// hunt runs through the same checks as `hunt_fish` using the intent's slippage bound,
+/// the keeper is paid the escrowed tip and the intent is consumed, even when the prey
+/// escapes and the hunter pays the failed-hunt fee. As in `hunt_fish`, the call must be
+/// the last top-level instruction of its transaction, and season stats are recorded.
+pub fn handle(ctx: Context<ExecuteHuntIntent>) -> Result<()> {
+    let ocean = &mut ctx.accounts.ocean;
+    let hunter = &mut ctx.accounts.hunter;
+    let prey = &mut ctx.accounts.prey;
+    let intent = &ctx.accounts.intent;
+    let vault = &ctx.accounts.vault;
+    let admin = &ctx.accounts.admin;
+    let keeper = &ctx.accounts.keeper;
+    let system_program = &ctx.accounts.system_program;
+
+    let current_time = Clock::get()?.unix_timestamp;
+
+    ensure_roll_is_final(&ctx.accounts.instructions)?;
+    require!(current_time <= intent.deadline, ErrorCode::IntentExpired);
+    // A transferred hunter no longer acts for the owner who posted the intent.
+    hunter.ensure_owned_by(&intent.owner)?;
+    let expected_prey_share = intent
+        .expected_share_for(prey.id, prey.share)
+        .ok_or(ErrorCode::InvalidIntent)?;
+
+    evaluate_hunt(
+        hunter,
+        prey,
+        expected_prey_share,
+        intent.max_slippage_bps,
+        current_time,
+    )
+    .ensure_passes()?;
+
+    check_hunting_mark_exclusivity(prey, hunter.id, current_time)?;
+
+    let value_before = share_to_value(ocean, hunter.share);
+    let income_before = hunter.total_hunt_income;
+    let roll = roll_escape(ocean, hunter, prey, current_time, &ctx.accounts.slot_hashes)?;
+    let caught = !roll.escaped();
+    if caught {
+        resolve_hunt(
+            ocean,
+            hunter,
+            prey,
+            vault,
+            admin,
+            system_program,
+            current_time,
+        )?;
+        release_name_if_dead(
+            prey,
+            &ctx.accounts.prey_name_registry,
+            &prey.to_account_info(),
+        )?;
+    } else {
+        resolve_failed_hunt(ocean, hunter, prey, vault, admin, &roll, current_time)?;
+    }
+
+    if track_season(
+        ctx.accounts.season.as_ref(),
+        ocean,
+        hunter,
+        value_before,
+        0,
+        current_time,
+    ) && caught
+    {
+        let income = hunter.total_hunt_income - income_before;
+        hunter.record_season_hunt(income);
+    }
+    if let Some(leaderboard) = ctx.accounts.leaderboard.as_mut() {
+        leaderboard.record(hunter, current_time);
+        leaderboard.record(prey, current_time);
+    }
+    unlock_achievements(
+        ctx.accounts.achievements.as_mut(),
+        ocean,
+        hunter,
+        0,
+        current_time,
+    );
+    if caught {
+        if let Some(profile) = ctx.accounts.hunter_profile.as_mut() {
+            profile.hunts = profile.hunts.saturating_add(1);
+        }
+        if let Some(profile) = ctx.accounts.prey_profile.as_mut() {
+            profile.record_loss(prey.id);
+        }
+    }
+
+    transfer_lamports(
+        &intent.to_account_info(),
+        &keeper.to_account_info(),
+        intent.tip,
+    )?;
+
+    emit!(HuntIntentExecuted {
+        hunter_id: hunter.id,
+        prey_id: prey.id,
+        keeper: keeper.key(),
+        tip: intent.tip,
+        caught,
+    });
+
+    Ok(())
+}
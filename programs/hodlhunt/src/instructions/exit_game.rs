@@ -1,4 +1,3 @@
-use crate::constants::fees;
 use crate::errors::ErrorCode;
 use crate::instructions::common::release_name_if_dead;
 use crate::{events::*, instructions::ExitGame, utils::*};
@@ -6,7 +5,7 @@ use anchor_lang::prelude::*;
 
 /// Allows a fish owner to withdraw from the ocean when conditions permit, distributing
 /// exit fees between admin and pool while transferring remaining value to the owner and
-/// updating ocean aggregates.
+/// updating ocean aggregates. Exit fees are reduced by the fish's loyalty discount.
 pub fn handle(ctx: Context<ExitGame>) -> Result<()> {
     let ocean = &mut ctx.accounts.ocean;
     let fish = &mut ctx.accounts.fish;
@@ -17,17 +16,15 @@ pub fn handle(ctx: Context<ExitGame>) -> Result<()> {
 
     fish.ensure_alive()?;
     fish.ensure_owned_by(&owner.key())?;
+    fish.ensure_not_trade_locked()?;
     require!(!ocean.is_storm, ErrorCode::ExitDuringStorm);
 
-    let total_value = share_to_value(ocean, fish.share);
-    let fee_component = total_value
-        .saturating_mul(fees::EXIT_FEE_BPS)
-        .saturating_div(fees::BASIS_POINTS_DIVISOR);
-    let fee_fishes = fee_component;
-    let fee_admin = fee_component;
-    let withdrawal = total_value
-        .saturating_sub(fee_fishes)
-        .saturating_sub(fee_admin);
+    let now = Clock::get()?.unix_timestamp;
+    let quote = exit_breakdown(ocean, fish, now);
+    let total_value = quote.total_value;
+    let fee_fishes = quote.pool_fee;
+    let fee_admin = quote.admin_fee;
+    let withdrawal = quote.to_player;
 
     require!(
         vault.lamports() >= withdrawal,
@@ -62,6 +59,13 @@ pub fn handle(ctx: Context<ExitGame>) -> Result<()> {
     fish.share = 0;
 
     release_name_if_dead(fish, &ctx.accounts.name_registry, &owner.to_account_info())?;
+    if let Some(leaderboard) = ctx.accounts.leaderboard.as_mut() {
+        leaderboard.record(fish, now);
+    }
+    if let Some(profile) = ctx.accounts.profile.as_mut() {
+        profile.remove_fish(fish.id);
+        profile.lifetime_withdrawals = profile.lifetime_withdrawals.saturating_add(withdrawal);
+    }
 
     emit!(FishExited {
         fish_id: fish.id,
@@ -72,6 +76,7 @@ pub fn handle(ctx: Context<ExitGame>) -> Result<()> {
         pool_fee: fee_fishes,
         to_player: withdrawal,
         new_balance: ocean.balance_fishes,
+        fee_discount_bps: quote.loyalty_discount_bps,
     });
 
     Ok(())
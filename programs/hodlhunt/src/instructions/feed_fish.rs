@@ -1,12 +1,19 @@
-use crate::constants::fees;
 use crate::errors::ErrorCode;
-use crate::{events::*, instructions::FeedFish, utils::*, Fish};
+use crate::instructions::common::{
+    apply_feeding, authorize_fish_action, pay_admin_fee, record_feeding, AdminFeeCuts,
+    FeedingTrackers,
+};
+use crate::state::Delegation;
+use crate::{instructions::FeedFish, utils::*};
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program as spl_prog;
 
-/// Transfers the feeding payment, applies commissions, updates share balances and marks
-/// the fish as recently fed. Resets hunt-related flags and enforces minimum feeding amounts
-/// derived from the current ocean state and previous hunt rewards.
+/// Transfers the feeding payment and commissions from the owner, then updates share
+/// balances and marks the fish as recently fed. Enforces minimum feeding amounts derived
+/// from the current ocean state and previous hunt rewards; the commission is reduced by
+/// the fish's loyalty discount. A delegate or session key may sign instead of the owner,
+/// paying from its own wallet within its cap or budget. A supplied running season takes
+/// its pot cut and enters the fish; the feeding counts as a contribution to its season.
 pub fn handle(ctx: Context<FeedFish>, feeding_amount: u64) -> Result<()> {
     let ocean = &mut ctx.accounts.ocean;
     let fish = &mut ctx.accounts.fish;
@@ -16,21 +23,30 @@ pub fn handle(ctx: Context<FeedFish>, feeding_amount: u64) -> Result<()> {
     let system_program = &ctx.accounts.system_program;
 
     fish.ensure_alive()?;
-    fish.ensure_owned_by(&owner.key())?;
+    fish.ensure_not_trade_locked()?;
 
-    let min_required_cost = min_feeding_amount(ocean, fish);
+    let now = Clock::get()?.unix_timestamp;
+    let quote = feed_breakdown(ocean, fish, feeding_amount, now);
 
     require!(
-        feeding_amount >= min_required_cost,
+        feeding_amount >= quote.min_required,
         ErrorCode::InsufficientFeedingAmount
     );
 
-    let commission = feeding_amount / fees::FEED_COMMISSION_DIVISOR;
-    let admin_fee = commission / fees::FEE_SPLIT_DIVISOR;
-    let pool_fee = commission - admin_fee;
-    let total_cost = feeding_amount + commission;
+    authorize_fish_action(
+        fish,
+        &owner.key(),
+        ctx.accounts.delegation.as_mut(),
+        ctx.accounts.session.as_mut(),
+        Delegation::PERMISSION_FEED,
+        quote.total_cost,
+        now,
+    )?;
+
+    let admin_fee = quote.admin_fee;
+    let pool_fee = quote.pool_fee;
 
-    require!(owner.lamports() >= total_cost, ErrorCode::InsufficientFunds);
+    require!(owner.lamports() >= quote.total_cost, ErrorCode::InsufficientFunds);
 
     let ix_vault = spl_prog::system_instruction::transfer(
         &owner.key(),
@@ -46,42 +62,35 @@ pub fn handle(ctx: Context<FeedFish>, feeding_amount: u64) -> Result<()> {
         ],
     )?;
 
-    let ix_admin = spl_prog::system_instruction::transfer(&owner.key(), &admin.key(), admin_fee);
-    spl_prog::program::invoke(
-        &ix_admin,
-        &[
-            owner.to_account_info(),
-            admin.to_account_info(),
-            system_program.to_account_info(),
-        ],
+    let bound_referrer = ctx.accounts.profile.as_ref().map(|profile| profile.referrer);
+    pay_admin_fee(
+        owner,
+        admin,
+        AdminFeeCuts {
+            referral: ctx.accounts.referral.as_mut(),
+            bound_referrer,
+            season: ctx.accounts.season.as_mut(),
+        },
+        system_program,
+        admin_fee,
     )?;
 
-    ocean.balance_fishes = ocean
-        .balance_fishes
-        .saturating_add(feeding_amount + pool_fee);
-
-    let added_share = new_share(ocean, feeding_amount);
-    fish.share = fish.share.saturating_add(added_share);
-    ocean.total_shares = ocean.total_shares.saturating_add(added_share);
-
-    let now = Clock::get()?.unix_timestamp;
-    fish.last_fed_at = now;
-    fish.marked_by_hunter_id = 0;
-    fish.mark_placed_at = 0;
-    fish.mark_expires_at = 0;
-    fish.mark_cost = 0;
-    fish.can_hunt_after = now + Fish::FEEDING_COOLDOWN;
-    fish.received_from_hunt_value = 0;
-
-    emit!(FishFed {
-        fish_id: fish.id,
-        owner: fish.owner,
-        added_share,
-        base_cost: feeding_amount,
-        admin_fee,
-        pool_fee,
-        new_share: fish.share,
-        new_value: share_to_value(ocean, fish.share),
-    });
+    let value_before = share_to_value(ocean, fish.share);
+    apply_feeding(ocean, fish, &quote, now);
+    record_feeding(
+        FeedingTrackers {
+            season: ctx.accounts.season.as_ref(),
+            leaderboard: ctx.accounts.leaderboard.as_mut(),
+            achievements: ctx.accounts.achievements.as_mut(),
+        },
+        ocean,
+        fish,
+        value_before,
+        feeding_amount,
+        now,
+    );
+    if let Some(profile) = ctx.accounts.profile.as_mut() {
+        profile.record_deposit(feeding_amount);
+    }
     Ok(())
 }
@@ -0,0 +1,168 @@
+use crate::constants::batch;
+use crate::errors::ErrorCode;
+use crate::instructions::common::{apply_feeding, record_feeding, FeedingTrackers};
+use crate::seeds::derive_fish_pda;
+use crate::state::{Fish, Ocean};
+use crate::views::{FeedManyItem, FeedManyResult, FeedQuote};
+use crate::{instructions::FeedMany, utils::*};
+use anchor_lang::prelude::*;
+
+/// Feeds every fish passed in remaining accounts with the matching entry of `amounts`,
+/// collecting the whole payment in one transfer to the vault and one to the admin.
+/// Each fish is validated as in `feed_fish`; by default any failure aborts the batch,
+/// while `skip_failed` leaves failing fish unfed and reports their error codes instead.
+/// Supplied season, leaderboard and achievements accounts are updated for every fed fish.
+pub fn handle<'info>(
+    ctx: Context<'_, '_, 'info, 'info, FeedMany<'info>>,
+    amounts: Vec<u64>,
+    skip_failed: bool,
+) -> Result<FeedManyResult> {
+    let ocean = &mut ctx.accounts.ocean;
+    let vault = &ctx.accounts.vault;
+    let owner = &ctx.accounts.owner;
+    let admin = &ctx.accounts.admin;
+    let system_program = &ctx.accounts.system_program;
+
+    require!(
+        !amounts.is_empty()
+            && amounts.len() <= batch::MAX_FEED_BATCH
+            && amounts.len() == ctx.remaining_accounts.len(),
+        ErrorCode::InvalidBatch
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    let mut result = FeedManyResult::default();
+    let mut to_vault: u64 = 0;
+    let mut to_admin: u64 = 0;
+
+    for (info, &amount) in ctx.remaining_accounts.iter().zip(amounts.iter()) {
+        let trackers = FeedingTrackers {
+            season: ctx.accounts.season.as_ref(),
+            leaderboard: ctx.accounts.leaderboard.as_mut(),
+            achievements: ctx.accounts.achievements.as_mut(),
+        };
+        match feed_one(
+            ctx.program_id,
+            ocean,
+            info,
+            &owner.key(),
+            amount,
+            trackers,
+            now,
+        ) {
+            Ok((fish_id, quote)) => {
+                to_vault = to_vault
+                    .checked_add(quote.feeding_amount + quote.pool_fee)
+                    .ok_or(ErrorCode::MathOverflow)?;
+                to_admin = to_admin
+                    .checked_add(quote.admin_fee)
+                    .ok_or(ErrorCode::MathOverflow)?;
+                result.fed += 1;
+                result.items.push(FeedManyItem {
+                    fish_id,
+                    feeding_amount: amount,
+                    shares_minted: quote.shares_minted,
+                    error_code: 0,
+                });
+            }
+            Err(err) if skip_failed => {
+                result.skipped += 1;
+                result.items.push(FeedManyItem {
+                    fish_id: read_fish_id(info),
+                    feeding_amount: amount,
+                    shares_minted: 0,
+                    error_code: error_code(&err),
+                });
+            }
+            Err(err) => return Err(err),
+        }
+    }
+
+    result.total_cost = to_vault + to_admin;
+    require!(
+        owner.lamports() >= result.total_cost,
+        ErrorCode::InsufficientFunds
+    );
+
+    transfer_from_signer(
+        &owner.to_account_info(),
+        vault,
+        &system_program.to_account_info(),
+        to_vault,
+    )?;
+    transfer_from_signer(
+        &owner.to_account_info(),
+        admin,
+        &system_program.to_account_info(),
+        to_admin,
+    )?;
+
+    if let Some(profile) = ctx.accounts.profile.as_mut() {
+        profile.record_deposit(
+            result
+                .items
+                .iter()
+                .filter(|item| item.error_code == 0)
+                .map(|item| item.feeding_amount)
+                .sum(),
+        );
+    }
+
+    Ok(result)
+}
+
+/// Validates and feeds a single fish of the batch, writing it back immediately so a fish
+/// listed twice sees its updated state.
+fn feed_one<'info>(
+    program_id: &Pubkey,
+    ocean: &mut Account<Ocean>,
+    info: &'info AccountInfo<'info>,
+    owner: &Pubkey,
+    amount: u64,
+    trackers: FeedingTrackers,
+    now: i64,
+) -> Result<(u64, FeedQuote)> {
+    require!(info.is_writable, ErrorCode::InvalidBatch);
+    let mut fish: Account<'info, Fish> = Account::try_from(info)?;
+    require_keys_eq!(
+        fish.key(),
+        derive_fish_pda(program_id, &ocean.key(), fish.id).0,
+        ErrorCode::InvalidBatch
+    );
+
+    fish.ensure_alive()?;
+    fish.ensure_owned_by(owner)?;
+    fish.ensure_not_trade_locked()?;
+
+    let quote = feed_breakdown(ocean, &fish, amount, now);
+    require!(
+        amount >= quote.min_required,
+        ErrorCode::InsufficientFeedingAmount
+    );
+
+    let value_before = share_to_value(ocean, fish.share);
+    apply_feeding(ocean, &mut fish, &quote, now);
+    record_feeding(trackers, ocean, &mut fish, value_before, amount, now);
+    fish.exit(program_id)?;
+    Ok((fish.id, quote))
+}
+
+/// Best-effort fish id for reporting a skipped entry; zero when the account is not a fish.
+fn read_fish_id(info: &AccountInfo) -> u64 {
+    let data = match info.try_borrow_data() {
+        Ok(data) => data,
+        Err(_) => return 0,
+    };
+    data.get(8..16)
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(u64::from_le_bytes)
+        .unwrap_or(0)
+}
+
+/// Maps an error to the numeric code reported for a skipped entry.
+fn error_code(err: &Error) -> u32 {
+    match err {
+        Error::AnchorError(err) => err.error_code_number,
+        Error::ProgramError(_) => u32::MAX,
+    }
+}
@@ -0,0 +1,62 @@
+use crate::constants::seasons;
+use crate::errors::ErrorCode;
+use crate::seeds::derive_fish_pda;
+use crate::state::{Fish, SeasonWinner};
+use crate::{events::*, instructions::FinalizeSeason};
+use anchor_lang::prelude::*;
+
+/// Submits candidate fish for a finished season. Anyone may call this repeatedly during
+/// the finalization window; each living fish that took part in the season is merged
+/// into the standings by score, and the rest are ignored.
+pub fn handle<'info>(ctx: Context<'_, '_, 'info, 'info, FinalizeSeason<'info>>) -> Result<()> {
+    let ocean_key = ctx.accounts.ocean.key();
+    let season = &mut ctx.accounts.season;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(now >= season.end_time, ErrorCode::SeasonNotEnded);
+    require!(
+        now < season.finalization_closes_at(),
+        ErrorCode::SeasonFinalizationClosed
+    );
+    require!(
+        !ctx.remaining_accounts.is_empty()
+            && ctx.remaining_accounts.len() <= seasons::MAX_CANDIDATES,
+        ErrorCode::InvalidBatch
+    );
+
+    for info in ctx.remaining_accounts.iter() {
+        let fish: Account<'info, Fish> = Account::try_from(info)?;
+        require_keys_eq!(
+            fish.key(),
+            derive_fish_pda(ctx.program_id, &ocean_key, fish.id).0,
+            ErrorCode::InvalidBatch
+        );
+        if fish.share == 0 || fish.season_id != season.season_id || fish.season_score() == 0 {
+            continue;
+        }
+        season.submit_candidate(SeasonWinner {
+            fish_id: fish.id,
+            owner: fish.owner,
+            score: fish.season_score(),
+            hunts: fish.season_hunts,
+            hunt_income: fish.season_hunt_income,
+        });
+    }
+
+    let mut fish_ids = [0u64; seasons::PRIZE_RANKS];
+    let mut scores = [0u64; seasons::PRIZE_RANKS];
+    for (rank, winner) in season.winners.iter().enumerate() {
+        fish_ids[rank] = winner.fish_id;
+        scores[rank] = winner.score;
+    }
+
+    emit!(SeasonStandingsUpdated {
+        season_id: season.season_id,
+        winner_count: season.winner_count,
+        fish_ids,
+        scores,
+        prize_pot: season.prize_pot,
+    });
+
+    Ok(())
+}
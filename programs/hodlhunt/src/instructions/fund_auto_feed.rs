@@ -0,0 +1,44 @@
+use crate::errors::ErrorCode;
+use crate::state::AutoFeed;
+use crate::{events::*, instructions::FundAutoFeed, utils::*};
+use anchor_lang::prelude::*;
+
+/// Deposits lamports into the fish's auto-feed escrow and sets the per-feeding cap a
+/// keeper may spend. The escrow is created on first use and only serves the owner that
+/// funded it.
+pub fn handle(ctx: Context<FundAutoFeed>, amount: u64, max_feed_amount: u64) -> Result<()> {
+    let fish = &ctx.accounts.fish;
+    let auto_feed = &mut ctx.accounts.auto_feed;
+    let owner = &ctx.accounts.owner;
+    let system_program = &ctx.accounts.system_program;
+
+    fish.ensure_alive()?;
+
+    if auto_feed.owner == Pubkey::default() {
+        auto_feed.fish = fish.key();
+        auto_feed.fish_id = fish.id;
+        auto_feed.owner = owner.key();
+        auto_feed.bump = ctx.bumps.auto_feed;
+    }
+
+    require!(owner.lamports() >= amount, ErrorCode::InsufficientFunds);
+    transfer_from_signer(
+        &owner.to_account_info(),
+        &auto_feed.to_account_info(),
+        &system_program.to_account_info(),
+        amount,
+    )?;
+
+    auto_feed.max_feed_amount = max_feed_amount;
+    auto_feed.total_deposited = auto_feed.total_deposited.saturating_add(amount);
+
+    emit!(AutoFeedFunded {
+        fish_id: fish.id,
+        owner: owner.key(),
+        amount,
+        max_feed_amount,
+        available: AutoFeed::available(&auto_feed.to_account_info())?,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,13 @@
+use crate::{instructions::GetFishSnapshot, views::FishSnapshot};
+use anchor_lang::prelude::*;
+
+/// Read-only view that returns a Borsh-encoded snapshot of the fish with its derived value,
+/// hunger timing, hunting eligibility and mark status.
+pub fn handle(ctx: Context<GetFishSnapshot>) -> Result<FishSnapshot> {
+    let now = Clock::get()?.unix_timestamp;
+    Ok(FishSnapshot::build(
+        &ctx.accounts.ocean,
+        &ctx.accounts.fish,
+        now,
+    ))
+}
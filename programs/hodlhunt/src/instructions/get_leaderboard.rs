@@ -0,0 +1,10 @@
+use crate::{instructions::GetLeaderboard, views::LeaderboardSnapshot};
+use anchor_lang::prelude::*;
+
+/// Read-only view that returns both leaderboards with current fish values.
+pub fn handle(ctx: Context<GetLeaderboard>) -> Result<LeaderboardSnapshot> {
+    Ok(LeaderboardSnapshot::build(
+        &ctx.accounts.ocean,
+        &ctx.accounts.leaderboard,
+    ))
+}
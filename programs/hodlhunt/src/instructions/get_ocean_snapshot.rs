@@ -0,0 +1,9 @@
+use crate::{instructions::GetOceanSnapshot, views::OceanSnapshot};
+use anchor_lang::prelude::*;
+
+/// Read-only view that returns a Borsh-encoded snapshot of the ocean with its value per
+/// share, current mode, next mode change and fee parameters.
+pub fn handle(ctx: Context<GetOceanSnapshot>) -> Result<OceanSnapshot> {
+    let now = Clock::get()?.unix_timestamp;
+    Ok(OceanSnapshot::build(&ctx.accounts.ocean, now))
+}
@@ -0,0 +1,45 @@
+use crate::errors::ErrorCode;
+use crate::state::Delegation;
+use crate::{events::*, instructions::GrantDelegation};
+use anchor_lang::prelude::*;
+
+/// Creates or replaces the owner's grant for `delegate`. Replacing a grant resets the
+/// amount spent under it.
+pub fn handle(
+    ctx: Context<GrantDelegation>,
+    delegate: Pubkey,
+    permissions: u8,
+    expires_at: i64,
+    spend_cap: u64,
+) -> Result<()> {
+    let delegation = &mut ctx.accounts.delegation;
+    let owner = &ctx.accounts.owner;
+
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(delegate != owner.key(), ErrorCode::InvalidDelegation);
+    require!(
+        permissions != 0 && permissions & !Delegation::PERMISSION_ALL == 0,
+        ErrorCode::InvalidDelegation
+    );
+    require!(expires_at > now, ErrorCode::InvalidExpiry);
+
+    delegation.owner = owner.key();
+    delegation.delegate = delegate;
+    delegation.permissions = permissions;
+    delegation.expires_at = expires_at;
+    delegation.spend_cap = spend_cap;
+    delegation.spent = 0;
+    delegation.created_at = now;
+    delegation.bump = ctx.bumps.delegation;
+
+    emit!(DelegationGranted {
+        owner: owner.key(),
+        delegate,
+        permissions,
+        expires_at,
+        spend_cap,
+    });
+
+    Ok(())
+}
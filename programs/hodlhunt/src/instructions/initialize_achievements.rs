@@ -0,0 +1,14 @@
+use crate::instructions::InitializeAchievements;
+use anchor_lang::prelude::*;
+
+/// Opens the caller's achievements account so handlers can unlock achievements for
+/// the caller's fish. Achievements earned earlier unlock on the fish's next action.
+pub fn handle(ctx: Context<InitializeAchievements>) -> Result<()> {
+    let achievements = &mut ctx.accounts.achievements;
+
+    achievements.owner = ctx.accounts.owner.key();
+    achievements.created_at = Clock::get()?.unix_timestamp;
+    achievements.bump = ctx.bumps.achievements;
+
+    Ok(())
+}
@@ -0,0 +1,14 @@
+use crate::instructions::InitializeLeaderboard;
+use anchor_lang::prelude::*;
+
+/// Creates the ocean's leaderboard account. Boards start empty and fill as fish are
+/// created, fed, hunt or exit with the leaderboard passed in.
+pub fn handle(ctx: Context<InitializeLeaderboard>) -> Result<()> {
+    let leaderboard = &mut ctx.accounts.leaderboard;
+
+    leaderboard.ocean = ctx.accounts.ocean.key();
+    leaderboard.updated_at = Clock::get()?.unix_timestamp;
+    leaderboard.bump = ctx.bumps.leaderboard;
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use crate::errors::ErrorCode;
+use crate::{events::*, instructions::ListFish, Fish};
+use anchor_lang::prelude::*;
+
+/// Lists a fish on the marketplace either at a fixed price or as an auction ending at
+/// `auction_ends_at`, in which case `price` is the minimum opening bid. The fish stays
+/// frozen until the listing is settled or cancelled.
+pub fn handle(ctx: Context<ListFish>, price: u64, auction_ends_at: Option<i64>) -> Result<()> {
+    let fish = &mut ctx.accounts.fish;
+    let listing = &mut ctx.accounts.listing;
+    let seller = &ctx.accounts.seller;
+
+    let now = Clock::get()?.unix_timestamp;
+
+    fish.ensure_alive()?;
+    fish.ensure_owned_by(&seller.key())?;
+    fish.ensure_not_trade_locked()?;
+
+    let auction_ends_at = auction_ends_at.unwrap_or(0);
+    if auction_ends_at != 0 {
+        require!(auction_ends_at > now, ErrorCode::InvalidExpiry);
+    }
+
+    listing.fish = fish.key();
+    listing.fish_id = fish.id;
+    listing.seller = seller.key();
+    listing.price = price;
+    listing.auction_ends_at = auction_ends_at;
+    listing.highest_bid = 0;
+    listing.highest_bidder = Pubkey::default();
+    listing.open_bids = 0;
+    listing.is_active = true;
+    listing.created_at = now;
+    listing.bump = ctx.bumps.listing;
+
+    fish.trade_lock = Fish::TRADE_LOCK_LISTING;
+
+    emit!(FishListed {
+        fish_id: fish.id,
+        seller: seller.key(),
+        price,
+        auction_ends_at,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,109 @@
+use crate::constants::fees;
+use crate::errors::ErrorCode;
+use crate::state::Fish;
+use crate::instructions::common::release_name_if_dead;
+use crate::{events::*, instructions::MergeFish, utils::*};
+use anchor_lang::prelude::*;
+
+/// Folds the share of `source` into `target` for the same owner. The owner pays a merge
+/// fee on the absorbed value, the merged fish keeps the stricter cooldown, hunger,
+/// protection and shield usage of the two, and the absorbed fish releases its name and
+/// is closed.
+pub fn handle(ctx: Context<MergeFish>) -> Result<()> {
+    let ocean = &mut ctx.accounts.ocean;
+    let target = &mut ctx.accounts.target;
+    let source = &mut ctx.accounts.source;
+    let vault = &ctx.accounts.vault;
+    let owner = &ctx.accounts.owner;
+    let admin = &ctx.accounts.admin;
+    let system_program = &ctx.accounts.system_program;
+
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(target.id != source.id, ErrorCode::CannotMergeWithSelf);
+    target.ensure_alive()?;
+    source.ensure_alive()?;
+    target.ensure_owned_by(&owner.key())?;
+    source.ensure_owned_by(&owner.key())?;
+    target.ensure_not_trade_locked()?;
+    source.ensure_not_trade_locked()?;
+    target.ensure_unmarked(now)?;
+    source.ensure_unmarked(now)?;
+
+    let merge_fee = share_to_value(ocean, source.share)
+        .saturating_mul(fees::MERGE_FEE_BPS)
+        .saturating_div(fees::BASIS_POINTS_DIVISOR);
+    let admin_fee = merge_fee / fees::FEE_SPLIT_DIVISOR;
+    let pool_fee = merge_fee - admin_fee;
+    require!(owner.lamports() >= merge_fee, ErrorCode::InsufficientFunds);
+
+    transfer_from_signer(
+        &owner.to_account_info(),
+        vault,
+        &system_program.to_account_info(),
+        pool_fee,
+    )?;
+    transfer_from_signer(
+        &owner.to_account_info(),
+        admin,
+        &system_program.to_account_info(),
+        admin_fee,
+    )?;
+    ocean.balance_fishes = ocean.balance_fishes.saturating_add(pool_fee);
+
+    let absorbed_share = source.share;
+    target.share = target.share.saturating_add(absorbed_share);
+    let target_value = share_to_value(ocean, target.share);
+    target.record_season_activity(target_value, share_to_value(ocean, absorbed_share), 0, now);
+
+    target.last_fed_at = target.last_fed_at.min(source.last_fed_at);
+    target.last_hunt_at = target.last_hunt_at.max(source.last_hunt_at);
+    target.can_hunt_after = target.can_hunt_after.max(source.can_hunt_after);
+    target.is_protected = target.is_protected && source.is_protected;
+    target.protection_ends_at = target.protection_ends_at.min(source.protection_ends_at);
+    target.last_shield_at = target.last_shield_at.max(source.last_shield_at);
+    target.shield_seconds_used = target
+        .shield_seconds_in_season(now)
+        .max(source.shield_seconds_in_season(now));
+    target.shield_season = Fish::shield_season_at(now);
+    target.received_from_hunt_value = target
+        .received_from_hunt_value
+        .min(source.received_from_hunt_value);
+    target.hunting_marks_placed = target.hunting_marks_placed.max(source.hunting_marks_placed);
+    target.total_hunts = target.total_hunts.saturating_add(source.total_hunts);
+    target.total_hunt_income = target
+        .total_hunt_income
+        .saturating_add(source.total_hunt_income);
+
+    source.share = 0;
+    release_name_if_dead(
+        source,
+        &ctx.accounts.source_name_registry,
+        &owner.to_account_info(),
+    )?;
+
+    ocean.total_fish_count = ocean
+        .total_fish_count
+        .checked_sub(1)
+        .ok_or(ErrorCode::MathOverflow)?;
+    if let Some(profile) = ctx.accounts.profile.as_mut() {
+        profile.remove_fish(source.id);
+    }
+    if let Some(leaderboard) = ctx.accounts.leaderboard.as_mut() {
+        leaderboard.record(target, now);
+        leaderboard.record(source, now);
+    }
+
+    emit!(FishMerged {
+        target_id: target.id,
+        source_id: source.id,
+        owner: owner.key(),
+        absorbed_share,
+        new_share: target.share,
+        merge_fee,
+        admin_fee,
+        pool_fee,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use crate::errors::ErrorCode;
+use crate::seeds::derive_legacy_fish_pda;
+use crate::state::{Fish, LegacyFish};
+use crate::{events::*, instructions::MigrateFish, utils::*};
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+/// Moves a fish from its legacy owner-keyed PDA into the ocean-keyed layout. The legacy
+/// account is decoded, verified against its original seeds, copied field by field and
+/// closed back to the owner.
+pub fn handle(ctx: Context<MigrateFish>, fish_id: u64) -> Result<()> {
+    let legacy_info = &ctx.accounts.legacy_fish;
+    let fish = &mut ctx.accounts.fish;
+    let owner = &ctx.accounts.owner;
+
+    require_keys_eq!(*legacy_info.owner, crate::ID, ErrorCode::InvalidLegacyFish);
+    let (expected_pda, _bump) = derive_legacy_fish_pda(&crate::ID, &owner.key(), fish_id);
+    require_keys_eq!(
+        legacy_info.key(),
+        expected_pda,
+        ErrorCode::InvalidLegacyFish
+    );
+
+    let legacy = {
+        let data = legacy_info.try_borrow_data()?;
+        require!(
+            data.len() > Fish::DISCRIMINATOR.len() && data.starts_with(Fish::DISCRIMINATOR),
+            ErrorCode::InvalidLegacyFish
+        );
+        let mut payload: &[u8] = &data[Fish::DISCRIMINATOR.len()..];
+        LegacyFish::deserialize(&mut payload).map_err(|_| ErrorCode::InvalidLegacyFish)?
+    };
+    require!(legacy.id == fish_id, ErrorCode::InvalidLegacyFish);
+    require_keys_eq!(legacy.owner, owner.key(), ErrorCode::NotFishOwner);
+
+    fish.id = legacy.id;
+    fish.owner = legacy.owner;
+    fish.share = legacy.share;
+    fish.name = legacy.name;
+    fish.created_at = legacy.created_at;
+    fish.last_fed_at = legacy.last_fed_at;
+    fish.last_hunt_at = legacy.last_hunt_at;
+    fish.can_hunt_after = legacy.can_hunt_after;
+    fish.is_protected = legacy.is_protected;
+    fish.protection_ends_at = legacy.protection_ends_at;
+    fish.total_hunts = legacy.total_hunts;
+    fish.total_hunt_income = legacy.total_hunt_income;
+    fish.received_from_hunt_value = legacy.received_from_hunt_value;
+    fish.hunting_marks_placed = legacy.hunting_marks_placed;
+    fish.last_mark_reset = legacy.last_mark_reset;
+    fish.marked_by_hunter_id = legacy.marked_by_hunter_id;
+    fish.mark_placed_at = legacy.mark_placed_at;
+    fish.mark_expires_at = legacy.mark_expires_at;
+    fish.mark_cost = legacy.mark_cost;
+
+    close_program_account(legacy_info, &owner.to_account_info())?;
+
+    emit!(FishMigrated {
+        fish_id: fish.id,
+        owner: fish.owner,
+        legacy_address: legacy_info.key(),
+        new_address: fish.key(),
+    });
+
+    Ok(())
+}
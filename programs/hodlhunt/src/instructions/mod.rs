@@ -1,27 +1,127 @@
+pub mod accept_bid;
+pub mod accept_transfer_offer;
+pub mod buy_listed_fish;
+pub mod buy_shield;
+pub mod cancel_bid;
+pub mod cancel_hunt_intent;
+pub mod cancel_listing;
+pub mod cancel_transfer_offer;
+pub mod check_hunt;
+pub mod claim_referral_earnings;
+pub mod claim_season_prize;
+pub mod close_auto_feed;
+pub mod close_listing;
+pub mod close_season;
 pub mod common;
 pub mod contexts;
 pub mod create_fish;
+pub mod create_hunt_intent;
+pub mod create_profile;
+pub mod create_session;
+pub mod create_transfer_offer;
+pub mod execute_auto_feed;
+pub mod execute_hunt_intent;
 pub mod exit_game;
 pub mod feed_fish;
+pub mod feed_many;
+pub mod finalize_season;
+pub mod fund_auto_feed;
 pub mod get_fish_info;
+pub mod get_fish_snapshot;
+pub mod get_leaderboard;
 pub mod get_new_share;
+pub mod get_ocean_snapshot;
 pub mod get_share_value;
+pub mod grant_delegation;
 pub mod hunt_fish;
+pub mod initialize_achievements;
+pub mod initialize_leaderboard;
 pub mod initialize_ocean;
+pub mod list_fish;
+pub mod merge_fish;
+pub mod migrate_fish;
+pub mod open_season;
+pub mod place_bid;
 pub mod place_hunting_mark;
+pub mod prune_leaderboard;
+pub mod quote_create;
+pub mod quote_exit;
+pub mod quote_feed;
+pub mod quote_hunt;
+pub mod quote_mark;
+pub mod quote_shield;
+pub mod reap_starved_fish;
+pub mod register_referrer;
 pub mod resurrect_fish;
+pub mod revoke_delegation;
+pub mod revoke_session;
+pub mod set_profile_handle;
+pub mod settle_auction;
+pub mod split_fish;
+pub mod sync_profile;
 pub mod transfer_fish;
 pub mod update_ocean_daily;
+pub mod withdraw_auto_feed;
 
+pub use contexts::accept_bid::*;
+pub use contexts::accept_transfer_offer::*;
+pub use contexts::buy_listed_fish::*;
+pub use contexts::buy_shield::*;
+pub use contexts::cancel_bid::*;
+pub use contexts::cancel_hunt_intent::*;
+pub use contexts::cancel_listing::*;
+pub use contexts::cancel_transfer_offer::*;
+pub use contexts::check_hunt::*;
+pub use contexts::claim_referral_earnings::*;
+pub use contexts::claim_season_prize::*;
+pub use contexts::close_auto_feed::*;
+pub use contexts::close_listing::*;
+pub use contexts::close_season::*;
 pub use contexts::create_fish::*;
+pub use contexts::create_hunt_intent::*;
+pub use contexts::create_profile::*;
+pub use contexts::create_session::*;
+pub use contexts::create_transfer_offer::*;
+pub use contexts::execute_auto_feed::*;
+pub use contexts::execute_hunt_intent::*;
 pub use contexts::exit_game::*;
 pub use contexts::feed_fish::*;
+pub use contexts::feed_many::*;
+pub use contexts::finalize_season::*;
+pub use contexts::fund_auto_feed::*;
 pub use contexts::get_fish_info::*;
+pub use contexts::get_fish_snapshot::*;
+pub use contexts::get_leaderboard::*;
 pub use contexts::get_new_share::*;
+pub use contexts::get_ocean_snapshot::*;
 pub use contexts::get_share_value::*;
+pub use contexts::grant_delegation::*;
 pub use contexts::hunt_fish::*;
+pub use contexts::initialize_achievements::*;
+pub use contexts::initialize_leaderboard::*;
 pub use contexts::initialize_ocean::*;
+pub use contexts::list_fish::*;
+pub use contexts::merge_fish::*;
+pub use contexts::migrate_fish::*;
+pub use contexts::open_season::*;
+pub use contexts::place_bid::*;
 pub use contexts::place_hunting_mark::*;
+pub use contexts::prune_leaderboard::*;
+pub use contexts::quote_create::*;
+pub use contexts::quote_exit::*;
+pub use contexts::quote_feed::*;
+pub use contexts::quote_hunt::*;
+pub use contexts::quote_mark::*;
+pub use contexts::quote_shield::*;
+pub use contexts::reap_starved_fish::*;
+pub use contexts::register_referrer::*;
 pub use contexts::resurrect_fish::*;
+pub use contexts::revoke_delegation::*;
+pub use contexts::revoke_session::*;
+pub use contexts::set_profile_handle::*;
+pub use contexts::settle_auction::*;
+pub use contexts::split_fish::*;
+pub use contexts::sync_profile::*;
 pub use contexts::transfer_fish::*;
 pub use contexts::update_ocean_daily::*;
+pub use contexts::withdraw_auto_feed::*;
@@ -0,0 +1,50 @@
+use crate::constants::seasons;
+use crate::errors::ErrorCode;
+use crate::{events::*, instructions::OpenSeason};
+use anchor_lang::prelude::*;
+
+/// Opens a season between `start_time` and `end_time` whose prize pot is funded with
+/// `pot_fee_bps` of every admin fee paid while it runs. Admin only. Season ids must
+/// increase and a season may not start before the previous one's finalization closes.
+pub fn handle(
+    ctx: Context<OpenSeason>,
+    season_id: u64,
+    start_time: i64,
+    end_time: i64,
+    pot_fee_bps: u64,
+) -> Result<()> {
+    let season = &mut ctx.accounts.season;
+    let now = Clock::get()?.unix_timestamp;
+
+    // Id zero is the "no season" marker on fish.
+    require!(
+        season_id > 0
+            && start_time < end_time
+            && end_time > now
+            && pot_fee_bps <= seasons::MAX_POT_FEE_BPS,
+        ErrorCode::InvalidSeason
+    );
+
+    season.ocean = ctx.accounts.ocean.key();
+    season.season_id = season_id;
+    season.start_time = start_time;
+    season.end_time = end_time;
+    season.pot_fee_bps = pot_fee_bps;
+    season.bump = ctx.bumps.season;
+
+    let schedule = &mut ctx.accounts.schedule;
+    require!(schedule.accepts(season), ErrorCode::SeasonOverlaps);
+    schedule.ocean = season.ocean;
+    schedule.last_season_id = season_id;
+    schedule.last_finalization_closes_at = season.finalization_closes_at();
+    schedule.bump = ctx.bumps.schedule;
+
+    emit!(SeasonOpened {
+        season_id,
+        start_time,
+        end_time,
+        pot_fee_bps,
+    });
+
+    Ok(())
+}
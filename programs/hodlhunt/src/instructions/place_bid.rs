@@ -0,0 +1,59 @@
+use crate::errors::ErrorCode;
+use crate::{events::*, instructions::PlaceBid, utils::*};
+use anchor_lang::prelude::*;
+
+/// Places or raises a bid on a live listing, escrowing the additional lamports in the
+/// bidder's bid PDA. Auction bids must meet the opening price and beat the current
+/// highest bid before the auction ends.
+pub fn handle(ctx: Context<PlaceBid>, amount: u64) -> Result<()> {
+    let fish = &ctx.accounts.fish;
+    let listing = &mut ctx.accounts.listing;
+    let bid = &mut ctx.accounts.bid;
+    let bidder = &ctx.accounts.bidder;
+    let system_program = &ctx.accounts.system_program;
+
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(listing.is_live(fish), ErrorCode::ListingInactive);
+    require!(bidder.key() != listing.seller, ErrorCode::InvalidBid);
+    require!(amount > bid.amount, ErrorCode::BidTooLow);
+    if listing.is_auction() {
+        require!(!listing.has_ended(now), ErrorCode::AuctionEnded);
+        require!(
+            amount >= listing.price && amount > listing.highest_bid,
+            ErrorCode::BidTooLow
+        );
+    }
+
+    if bid.bidder == Pubkey::default() {
+        bid.listing = listing.key();
+        bid.bidder = bidder.key();
+        bid.bump = ctx.bumps.bid;
+        listing.open_bids = listing.open_bids.saturating_add(1);
+    }
+
+    let top_up = amount - bid.amount;
+    require!(bidder.lamports() >= top_up, ErrorCode::InsufficientFunds);
+    transfer_from_signer(
+        &bidder.to_account_info(),
+        &bid.to_account_info(),
+        &system_program.to_account_info(),
+        top_up,
+    )?;
+
+    bid.amount = amount;
+    bid.placed_at = now;
+    if amount > listing.highest_bid {
+        listing.highest_bid = amount;
+        listing.highest_bidder = bidder.key();
+    }
+
+    emit!(BidPlaced {
+        fish_id: listing.fish_id,
+        bidder: bidder.key(),
+        amount,
+        is_highest: listing.highest_bidder == bidder.key(),
+    });
+
+    Ok(())
+}
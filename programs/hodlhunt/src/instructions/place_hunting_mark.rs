@@ -1,13 +1,14 @@
-use crate::constants::marks;
 use crate::errors::ErrorCode;
-use crate::Fish;
+use crate::instructions::common::authorize_fish_action;
+use crate::state::Delegation;
 use crate::{events::*, instructions::PlaceHuntingMark, utils::*};
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program as spl_prog;
 
 /// Charges a hunter for placing an exclusive hunting mark on a prey fish within the
 /// permitted hunger window. Verifies mark limits, exclusivity, and hunter ownership
-/// before locking the mark and collecting the calculated fee.
+/// before locking the mark and collecting the calculated fee; a delegate's payment counts
+/// towards its spending cap or session budget.
 pub fn handle(ctx: Context<PlaceHuntingMark>) -> Result<()> {
     let ocean = &mut ctx.accounts.ocean;
     let hunter = &mut ctx.accounts.hunter;
@@ -21,44 +22,40 @@ pub fn handle(ctx: Context<PlaceHuntingMark>) -> Result<()> {
 
     hunter.ensure_alive()?;
     prey.ensure_alive()?;
-    hunter.ensure_owned_by(&hunter_owner.key())?;
+    hunter.ensure_not_trade_locked()?;
     require!(hunter.owner != prey.owner, ErrorCode::InvalidPrey);
     require!(hunter.key() != prey.key(), ErrorCode::InvalidPrey);
     require!(hunter.share > prey.share, ErrorCode::PreyTooHeavy);
-
-    let time_until_hungry = (prey.last_fed_at + Fish::PREY_COOLDOWN) - now;
     require!(
-        time_until_hungry <= marks::PLACEMENT_WINDOW_SECONDS && time_until_hungry > 0,
-        ErrorCode::MarkTooEarly
+        size_tier(size_ratio_bps(hunter.share, prey.share)).is_some(),
+        ErrorCode::SizeRatioTooLow
     );
 
+    let quote = mark_breakdown(ocean, prey, now);
+    let time_until_hungry = quote.time_until_hungry;
+    require!(quote.in_placement_window, ErrorCode::MarkTooEarly);
+
     prey.clear_expired_mark(now);
+    prey.refresh_protection(now);
     require!(prey.marked_by_hunter_id == 0, ErrorCode::MarkAlreadyActive);
 
-    let prey_value = share_to_value(ocean, prey.share);
-    let mark_cost_percent = if time_until_hungry <= marks::HIGH_RATE_THRESHOLD_SECONDS {
-        100
-    } else {
-        50
-    };
-
-    let mark_cost_raw = prey_value
-        .saturating_mul(mark_cost_percent)
-        .saturating_div(1000);
-
-    let min_mark_cost = spl_prog::native_token::LAMPORTS_PER_SOL / 100; // 0.01 SOL
-    let mark_cost = if mark_cost_raw < min_mark_cost {
-        min_mark_cost
-    } else {
-        mark_cost_raw
-    };
+    let mark_cost = quote.cost;
+    authorize_fish_action(
+        hunter,
+        &hunter_owner.key(),
+        ctx.accounts.delegation.as_mut(),
+        ctx.accounts.session.as_mut(),
+        Delegation::PERMISSION_MARK,
+        mark_cost,
+        now,
+    )?;
     require!(
         hunter_owner.lamports() >= mark_cost,
         ErrorCode::InsufficientFunds
     );
 
-    let to_pool = mark_cost / 2;
-    let to_admin = mark_cost - to_pool;
+    let to_pool = quote.pool_fee;
+    let to_admin = quote.admin_fee;
 
     let ix_vault =
         spl_prog::system_instruction::transfer(&hunter_owner.key(), &vault.key(), to_pool);
@@ -86,7 +83,7 @@ pub fn handle(ctx: Context<PlaceHuntingMark>) -> Result<()> {
 
     prey.marked_by_hunter_id = hunter.id;
     prey.mark_placed_at = now;
-    prey.mark_expires_at = prey.last_fed_at + Fish::PREY_COOLDOWN + Fish::MARK_EXCLUSIVITY_PERIOD;
+    prey.mark_expires_at = quote.expires_at;
     prey.mark_cost = mark_cost;
 
     hunter.hunting_marks_placed = hunter.hunting_marks_placed.saturating_add(1);
@@ -95,11 +92,11 @@ pub fn handle(ctx: Context<PlaceHuntingMark>) -> Result<()> {
         mark_id: prey.key(),
         hunter_id: hunter.id,
         prey_id: prey.id,
-        hunter_owner: hunter_owner.key(),
+        hunter_owner: hunter.owner,
         cost: mark_cost,
         expires_at: prey.mark_expires_at,
         time_until_hungry,
-        cost_percent: mark_cost_percent,
+        cost_percent: quote.cost_percent,
     });
 
     Ok(())
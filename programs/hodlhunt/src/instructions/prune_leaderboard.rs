@@ -0,0 +1,44 @@
+use crate::constants::leaderboard;
+use crate::errors::ErrorCode;
+use crate::instructions::PruneLeaderboard;
+use crate::seeds::derive_fish_pda;
+use crate::state::Fish;
+use anchor_lang::prelude::*;
+
+/// Refreshes the leaderboard entries of the fish passed in remaining accounts, with
+/// `fish_ids` naming the fish at each position. Anyone may call this: dead fish drop off
+/// the boards, as do fish whose account has been closed, and living fish are re-ranked
+/// with their current share and hunt income.
+pub fn handle<'info>(
+    ctx: Context<'_, '_, 'info, 'info, PruneLeaderboard<'info>>,
+    fish_ids: Vec<u64>,
+) -> Result<()> {
+    let ocean_key = ctx.accounts.ocean.key();
+    let leaderboard = &mut ctx.accounts.leaderboard;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(
+        !fish_ids.is_empty()
+            && fish_ids.len() <= leaderboard::MAX_PRUNE_BATCH
+            && fish_ids.len() == ctx.remaining_accounts.len(),
+        ErrorCode::InvalidBatch
+    );
+
+    for (info, &fish_id) in ctx.remaining_accounts.iter().zip(fish_ids.iter()) {
+        require_keys_eq!(
+            info.key(),
+            derive_fish_pda(ctx.program_id, &ocean_key, fish_id).0,
+            ErrorCode::InvalidBatch
+        );
+        // Only the program can own data at a fish address, so any other owner means the
+        // account was closed.
+        if info.owner == ctx.program_id {
+            let fish: Account<'info, Fish> = Account::try_from(info)?;
+            leaderboard.record(&fish, now);
+        } else {
+            leaderboard.remove(fish_id, now);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,22 @@
+use crate::constants::fees;
+use crate::{instructions::QuoteCreate, utils::*, views::CreateQuote};
+use anchor_lang::prelude::*;
+
+/// Read-only view that returns the fee and share breakdown of `create_fish`, or of
+/// `resurrect_fish` with its fee discount when `is_resurrection` is set.
+pub fn handle(
+    ctx: Context<QuoteCreate>,
+    deposit: u64,
+    is_resurrection: bool,
+) -> Result<CreateQuote> {
+    let fee_discount_bps = if is_resurrection {
+        fees::RESURRECTION_FEE_DISCOUNT_BPS
+    } else {
+        0
+    };
+    Ok(create_breakdown(
+        &ctx.accounts.ocean,
+        deposit,
+        fee_discount_bps,
+    ))
+}
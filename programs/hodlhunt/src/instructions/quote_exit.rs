@@ -0,0 +1,9 @@
+use crate::{instructions::QuoteExit, utils::*, views::ExitQuote};
+use anchor_lang::prelude::*;
+
+/// Read-only view that returns the payout and fees `exit_game` would apply to the fish,
+/// including its current loyalty discount.
+pub fn handle(ctx: Context<QuoteExit>) -> Result<ExitQuote> {
+    let now = Clock::get()?.unix_timestamp;
+    Ok(exit_breakdown(&ctx.accounts.ocean, &ctx.accounts.fish, now))
+}
@@ -0,0 +1,14 @@
+use crate::{instructions::QuoteFeed, utils::*, views::FeedQuote};
+use anchor_lang::prelude::*;
+
+/// Read-only view that returns the feeding breakdown `feed_fish` would apply for the
+/// given amount, including the minimum requirement and shares minted.
+pub fn handle(ctx: Context<QuoteFeed>, feeding_amount: u64) -> Result<FeedQuote> {
+    let now = Clock::get()?.unix_timestamp;
+    Ok(feed_breakdown(
+        &ctx.accounts.ocean,
+        &ctx.accounts.fish,
+        feeding_amount,
+        now,
+    ))
+}
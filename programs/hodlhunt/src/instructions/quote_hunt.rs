@@ -0,0 +1,14 @@
+use crate::{instructions::QuoteHunt, utils::*, views::HuntQuote};
+use anchor_lang::prelude::*;
+
+/// Read-only view that returns how `hunt_fish` would distribute the prey share if the
+/// hunt were executed now, the chance the prey escapes and the fee charged if it does.
+pub fn handle(ctx: Context<QuoteHunt>) -> Result<HuntQuote> {
+    let now = Clock::get()?.unix_timestamp;
+    Ok(hunt_breakdown(
+        &ctx.accounts.ocean,
+        &ctx.accounts.hunter,
+        &ctx.accounts.prey,
+        now,
+    ))
+}
@@ -0,0 +1,9 @@
+use crate::{instructions::QuoteMark, utils::*, views::MarkQuote};
+use anchor_lang::prelude::*;
+
+/// Read-only view that returns the cost `place_hunting_mark` would charge for the prey
+/// right now, and whether the prey is inside the placement window.
+pub fn handle(ctx: Context<QuoteMark>) -> Result<MarkQuote> {
+    let now = Clock::get()?.unix_timestamp;
+    Ok(mark_breakdown(&ctx.accounts.ocean, &ctx.accounts.prey, now))
+}
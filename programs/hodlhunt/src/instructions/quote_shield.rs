@@ -0,0 +1,14 @@
+use crate::{instructions::QuoteShield, utils::*, views::ShieldQuote};
+use anchor_lang::prelude::*;
+
+/// Read-only view that returns what `buy_shield` would charge for `duration` seconds of
+/// protection right now, along with the fish's shield cooldown and remaining season cap.
+pub fn handle(ctx: Context<QuoteShield>, duration: i64) -> Result<ShieldQuote> {
+    let now = Clock::get()?.unix_timestamp;
+    Ok(shield_breakdown(
+        &ctx.accounts.ocean,
+        &ctx.accounts.fish,
+        duration,
+        now,
+    ))
+}
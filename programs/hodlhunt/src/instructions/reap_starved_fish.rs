@@ -0,0 +1,68 @@
+use crate::constants::fees;
+use crate::errors::ErrorCode;
+use crate::instructions::common::{refund_bids, release_name_if_dead};
+use crate::{events::*, instructions::ReapStarvedFish, utils::*, Fish};
+use anchor_lang::prelude::*;
+
+/// Permissionlessly kills a fish that has gone unfed past `Fish::STARVATION_PERIOD`.
+/// Its shares are burned so the remaining value is redistributed to every other fish,
+/// minus a bounty paid from the vault to the caller. Any trade lock is cleared, which
+/// voids a pending offer or listing; a supplied listing is deactivated and the bids
+/// passed as `(bid, bidder)` pairs in remaining accounts are refunded.
+pub fn handle<'info>(ctx: Context<'_, '_, 'info, 'info, ReapStarvedFish<'info>>) -> Result<()> {
+    let ocean = &mut ctx.accounts.ocean;
+    let fish = &mut ctx.accounts.fish;
+    let vault = &ctx.accounts.vault;
+    let reaper = &ctx.accounts.reaper;
+
+    let now = Clock::get()?.unix_timestamp;
+
+    fish.ensure_alive()?;
+    require!(fish.is_starving(now), ErrorCode::NotStarving);
+
+    let starved_share = fish.share;
+    let starved_value = share_to_value(ocean, starved_share);
+    let bounty = starved_value
+        .saturating_mul(fees::REAPER_BOUNTY_BPS)
+        .saturating_div(fees::BASIS_POINTS_DIVISOR);
+
+    ocean.total_shares = ocean
+        .total_shares
+        .checked_sub(starved_share)
+        .ok_or(ErrorCode::MathOverflow)?;
+    ocean.balance_fishes = ocean
+        .balance_fishes
+        .checked_sub(bounty)
+        .ok_or(ErrorCode::MathOverflow)?;
+    transfer_lamports(vault, &reaper.to_account_info(), bounty)?;
+
+    fish.share = 0;
+    fish.trade_lock = Fish::TRADE_LOCK_NONE;
+    if let Some(listing) = ctx.accounts.listing.as_mut() {
+        listing.is_active = false;
+        let listing_key = listing.key();
+        refund_bids(listing, &listing_key, None, ctx.remaining_accounts)?;
+    }
+
+    release_name_if_dead(fish, &ctx.accounts.name_registry, &fish.to_account_info())?;
+
+    ocean.total_fish_count = ocean.total_fish_count.saturating_sub(1);
+    if let Some(profile) = ctx.accounts.profile.as_mut() {
+        profile.record_loss(fish.id);
+    }
+    if let Some(leaderboard) = ctx.accounts.leaderboard.as_mut() {
+        leaderboard.record(fish, now);
+    }
+
+    emit!(FishStarved {
+        fish_id: fish.id,
+        owner: fish.owner,
+        reaper: reaper.key(),
+        starved_share,
+        redistributed_value: starved_value.saturating_sub(bounty),
+        bounty,
+        last_fed_at: fish.last_fed_at,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,19 @@
+use crate::{events::*, instructions::RegisterReferrer};
+use anchor_lang::prelude::*;
+
+/// Opens the caller's referral account so it can be passed as the referrer on fish
+/// creation, resurrection and feeding.
+pub fn handle(ctx: Context<RegisterReferrer>) -> Result<()> {
+    let referral = &mut ctx.accounts.referral;
+    let referrer = &ctx.accounts.referrer;
+
+    referral.referrer = referrer.key();
+    referral.created_at = Clock::get()?.unix_timestamp;
+    referral.bump = ctx.bumps.referral;
+
+    emit!(ReferrerRegistered {
+        referrer: referrer.key(),
+    });
+
+    Ok(())
+}
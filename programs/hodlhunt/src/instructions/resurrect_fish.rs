@@ -1,13 +1,17 @@
 use super::common::{
     apply_creation_finance, init_new_fish_meta, mint_fish_share, reserve_name_registry,
+    track_season, unlock_achievements, AdminFeeCuts,
 };
 use crate::constants::fees;
 use crate::errors::ErrorCode;
+use crate::state::Achievements;
 use crate::{events::*, instructions::ResurrectFish};
 use anchor_lang::prelude::*;
 
 /// Revives a previously destroyed fish by reserving its name, processing the deposit
-/// with creation fees, minting new shares, and emitting a resurrection event.
+/// with discounted creation fees, minting new shares, and emitting a resurrection event.
+/// The new fish records its ancestor and generation, and the dead fish is marked as
+/// consumed so it can only be resurrected once.
 pub fn handle(ctx: Context<ResurrectFish>, name: String, deposit: u64) -> Result<()> {
     let ocean = &mut ctx.accounts.ocean;
     let old_fish = &mut ctx.accounts.old_fish;
@@ -22,15 +26,53 @@ pub fn handle(ctx: Context<ResurrectFish>, name: String, deposit: u64) -> Result
     require!(deposit >= fees::MIN_DEPOSIT_LAMPORTS, ErrorCode::MinimumDeposit);
     require!(owner.lamports() >= deposit, ErrorCode::InsufficientFunds);
     old_fish.ensure_dead()?;
+    old_fish.ensure_not_resurrected()?;
 
     reserve_name_registry(owner, &ctx.accounts.name_registry, system_program, trimmed)?;
 
+    let bound_referrer = ctx.accounts.profile.as_ref().map(|profile| profile.referrer);
     let (admin_fee, pool_fee, value) =
-        apply_creation_finance(owner, vault, admin, system_program, deposit)?;
+        apply_creation_finance(
+            owner,
+            vault,
+            admin,
+            system_program,
+            deposit,
+            fees::RESURRECTION_FEE_DISCOUNT_BPS,
+            AdminFeeCuts {
+                referral: ctx.accounts.referral.as_mut(),
+                bound_referrer,
+                season: ctx.accounts.season.as_mut(),
+            },
+        )?;
 
     ocean.balance_fishes = ocean.balance_fishes.saturating_add(pool_fee);
     let share = mint_fish_share(ocean, new_fish, value);
     init_new_fish_meta(ocean, new_fish, owner.key(), trimmed);
+    new_fish.parent_fish_id = old_fish.id;
+    new_fish.generation = old_fish.generation.saturating_add(1);
+    old_fish.resurrected_into_id = new_fish.id;
+    let created_at = new_fish.created_at;
+    track_season(
+        ctx.accounts.season.as_ref(),
+        ocean,
+        new_fish,
+        0,
+        value,
+        created_at,
+    );
+    unlock_achievements(
+        ctx.accounts.achievements.as_mut(),
+        ocean,
+        new_fish,
+        1 << Achievements::RESURRECTED,
+        created_at,
+    );
+    if let Some(profile) = ctx.accounts.profile.as_mut() {
+        profile.remove_fish(old_fish.id);
+        profile.add_fish(new_fish.id)?;
+        profile.record_deposit(value);
+    }
 
     emit!(FishResurrected {
         old_fish_id: old_fish.id,
@@ -41,6 +83,8 @@ pub fn handle(ctx: Context<ResurrectFish>, name: String, deposit: u64) -> Result
         deposit: value,
         admin_fee,
         pool_fee,
+        generation: new_fish.generation,
+        fee_discount_bps: fees::RESURRECTION_FEE_DISCOUNT_BPS,
     });
     Ok(())
 }
@@ -0,0 +1,15 @@
+use crate::{events::*, instructions::RevokeDelegation};
+use anchor_lang::prelude::*;
+
+/// Revokes a delegation and returns its rent to the owner.
+pub fn handle(ctx: Context<RevokeDelegation>) -> Result<()> {
+    let delegation = &ctx.accounts.delegation;
+
+    emit!(DelegationRevoked {
+        owner: delegation.owner,
+        delegate: delegation.delegate,
+        spent: delegation.spent,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use crate::{events::*, instructions::RevokeSession, utils::*};
+use anchor_lang::prelude::*;
+
+/// Ends a session immediately and returns its rent to the owner. When the session key
+/// co-signs, the unspent part of its budget is swept back to the owner as well; without
+/// it, the lamports stay on the key and only the session is closed.
+pub fn handle(ctx: Context<RevokeSession>) -> Result<()> {
+    let session = &ctx.accounts.session;
+    let owner = &ctx.accounts.owner;
+    let system_program = &ctx.accounts.system_program;
+
+    let swept = match ctx.accounts.session_signer.as_ref() {
+        Some(session_signer) => {
+            let unspent = session
+                .budget
+                .saturating_sub(session.spent)
+                .min(session_signer.lamports());
+            transfer_from_signer(
+                &session_signer.to_account_info(),
+                &owner.to_account_info(),
+                &system_program.to_account_info(),
+                unspent,
+            )?;
+            unspent
+        }
+        None => 0,
+    };
+
+    emit!(SessionRevoked {
+        owner: session.owner,
+        session_key: session.session_key,
+        spent: session.spent,
+        swept,
+    });
+
+    Ok(())
+}
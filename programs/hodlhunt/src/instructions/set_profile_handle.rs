@@ -0,0 +1,9 @@
+use crate::instructions::SetProfileHandle;
+use crate::state::PlayerProfile;
+use anchor_lang::prelude::*;
+
+/// Changes the display handle of the caller's profile.
+pub fn handle(ctx: Context<SetProfileHandle>, handle: String) -> Result<()> {
+    ctx.accounts.profile.handle = PlayerProfile::validate_handle(&handle)?.to_string();
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use super::common::{
+    close_listing_if_settled, complete_listing_sale, market_royalty, move_profile_fish,
+    refund_bids,
+};
+use crate::errors::ErrorCode;
+use crate::{instructions::SettleAuction, utils::*};
+use anchor_lang::prelude::*;
+
+/// Permissionlessly settles an ended auction in favour of its highest bid. The winning
+/// escrow pays the seller minus the admin royalty, the winner takes over the fish, and
+/// losing bids passed as `(bid, bidder)` pairs in remaining accounts are refunded.
+pub fn handle<'info>(ctx: Context<'_, '_, 'info, 'info, SettleAuction<'info>>) -> Result<()> {
+    let fish = &mut ctx.accounts.fish;
+    let listing = &mut ctx.accounts.listing;
+    let bid = &ctx.accounts.bid;
+    let seller = &ctx.accounts.seller;
+    let admin = &ctx.accounts.admin;
+
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(listing.is_live(fish), ErrorCode::ListingInactive);
+    require!(listing.is_auction(), ErrorCode::WrongListingKind);
+    require!(listing.has_ended(now), ErrorCode::AuctionNotEnded);
+    require_keys_eq!(bid.bidder, listing.highest_bidder, ErrorCode::InvalidBid);
+
+    let price = bid.amount;
+    let (royalty, to_seller) = market_royalty(price);
+    transfer_lamports(&bid.to_account_info(), seller, to_seller)?;
+    transfer_lamports(&bid.to_account_info(), admin, royalty)?;
+    listing.open_bids = listing.open_bids.saturating_sub(1);
+
+    complete_listing_sale(fish, listing, bid.bidder, price, royalty);
+    move_profile_fish(
+        ctx.accounts.seller_profile.as_mut(),
+        ctx.accounts.bidder_profile.as_mut(),
+        fish.id,
+    );
+    if let Some(leaderboard) = ctx.accounts.leaderboard.as_mut() {
+        leaderboard.record(fish, now);
+    }
+
+    let listing_key = listing.key();
+    refund_bids(
+        listing,
+        &listing_key,
+        Some(&bid.key()),
+        ctx.remaining_accounts,
+    )?;
+    close_listing_if_settled(listing, seller)
+}
@@ -0,0 +1,114 @@
+use super::common::reserve_name_registry;
+use crate::constants::fees;
+use crate::errors::ErrorCode;
+use crate::{events::*, instructions::SplitFish, utils::*};
+use anchor_lang::prelude::*;
+
+/// Moves `split_bps` of a fish's share into a newly named child fish. Both fish must keep
+/// at least the minimum deposit. The owner pays a creation-style fee on the split value,
+/// and the child inherits the parent's feeding, cooldown, protection and shield timers so
+/// splitting cannot dodge hunger or renew protection.
+pub fn handle(ctx: Context<SplitFish>, name: String, split_bps: u64) -> Result<()> {
+    let ocean = &mut ctx.accounts.ocean;
+    let parent = &mut ctx.accounts.parent;
+    let child = &mut ctx.accounts.child;
+    let vault = &ctx.accounts.vault;
+    let owner = &ctx.accounts.owner;
+    let admin = &ctx.accounts.admin;
+    let system_program = &ctx.accounts.system_program;
+
+    let now = Clock::get()?.unix_timestamp;
+    let trimmed = name.trim();
+
+    parent.ensure_alive()?;
+    parent.ensure_owned_by(&owner.key())?;
+    parent.ensure_not_trade_locked()?;
+    parent.ensure_unmarked(now)?;
+    require!(
+        split_bps > 0 && split_bps < fees::BASIS_POINTS_DIVISOR,
+        ErrorCode::InvalidSplit
+    );
+
+    let child_share = parent
+        .share
+        .saturating_mul(split_bps)
+        .saturating_div(fees::BASIS_POINTS_DIVISOR);
+    // Neither fish may end up below the minimum deposit a new fish must bring.
+    let child_value = share_to_value(ocean, child_share);
+    let remaining_value = share_to_value(ocean, parent.share.saturating_sub(child_share));
+    require!(
+        child_share > 0
+            && child_share < parent.share
+            && child_value >= fees::MIN_DEPOSIT_LAMPORTS
+            && remaining_value >= fees::MIN_DEPOSIT_LAMPORTS,
+        ErrorCode::InvalidSplit
+    );
+
+    reserve_name_registry(owner, &ctx.accounts.name_registry, system_program, trimmed)?;
+
+    let admin_fee = child_value / fees::CREATION_FEE_DIVISOR;
+    let pool_fee = child_value / fees::CREATION_FEE_DIVISOR;
+    require!(
+        owner.lamports() >= admin_fee + pool_fee,
+        ErrorCode::InsufficientFunds
+    );
+    transfer_from_signer(
+        &owner.to_account_info(),
+        vault,
+        &system_program.to_account_info(),
+        pool_fee,
+    )?;
+    transfer_from_signer(
+        &owner.to_account_info(),
+        admin,
+        &system_program.to_account_info(),
+        admin_fee,
+    )?;
+    ocean.balance_fishes = ocean.balance_fishes.saturating_add(pool_fee);
+
+    parent.share -= child_share;
+    let parent_value = share_to_value(ocean, parent.share);
+    parent.record_season_activity(parent_value, 0, child_value, now);
+
+    child.id = ocean.next_fish_id;
+    child.owner = owner.key();
+    child.name = trimmed.to_string();
+    child.share = child_share;
+    child.created_at = now;
+    child.last_fed_at = parent.last_fed_at;
+    child.last_hunt_at = parent.last_hunt_at;
+    child.can_hunt_after = parent.can_hunt_after;
+    child.is_protected = parent.is_protected;
+    child.protection_ends_at = parent.protection_ends_at;
+    child.last_shield_at = parent.last_shield_at;
+    child.shield_season = parent.shield_season;
+    child.shield_seconds_used = parent.shield_seconds_used;
+    child.hunting_marks_placed = parent.hunting_marks_placed;
+    child.last_mark_reset = parent.last_mark_reset;
+    child.parent_fish_id = parent.id;
+    child.generation = parent.generation.saturating_add(1);
+
+    ocean.total_fish_count = ocean.total_fish_count.saturating_add(1);
+    ocean.next_fish_id = ocean.next_fish_id.saturating_add(1);
+    if let Some(profile) = ctx.accounts.profile.as_mut() {
+        profile.add_fish(child.id)?;
+    }
+    if let Some(leaderboard) = ctx.accounts.leaderboard.as_mut() {
+        leaderboard.record(parent, now);
+        leaderboard.record(child, now);
+    }
+
+    emit!(FishSplit {
+        parent_id: parent.id,
+        child_id: child.id,
+        owner: owner.key(),
+        name: child.name.clone(),
+        split_bps,
+        child_share,
+        parent_new_share: parent.share,
+        admin_fee,
+        pool_fee,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use crate::constants::profiles;
+use crate::errors::ErrorCode;
+use crate::seeds::derive_fish_pda;
+use crate::state::Fish;
+use crate::{events::*, instructions::SyncProfile};
+use anchor_lang::prelude::*;
+
+/// Replaces the profile's fish list with the living fish among the remaining accounts
+/// that the caller owns. Used to list fish owned before the profile existed, or to
+/// repair the list after actions taken without the profile passed in.
+pub fn handle<'info>(ctx: Context<'_, '_, 'info, 'info, SyncProfile<'info>>) -> Result<()> {
+    let ocean_key = ctx.accounts.ocean.key();
+    let profile = &mut ctx.accounts.profile;
+    let owner = &ctx.accounts.owner;
+
+    require!(
+        ctx.remaining_accounts.len() <= profiles::MAX_FISH,
+        ErrorCode::ProfileFull
+    );
+
+    profile.fish_ids.clear();
+    for info in ctx.remaining_accounts.iter() {
+        let fish: Account<'info, Fish> = Account::try_from(info)?;
+        require_keys_eq!(
+            fish.key(),
+            derive_fish_pda(ctx.program_id, &ocean_key, fish.id).0,
+            ErrorCode::InvalidBatch
+        );
+        if fish.share > 0 && fish.owner == owner.key() {
+            profile.add_fish(fish.id)?;
+        }
+    }
+
+    emit!(ProfileSynced {
+        owner: owner.key(),
+        fish_count: profile.fish_ids.len() as u8,
+    });
+
+    Ok(())
+}
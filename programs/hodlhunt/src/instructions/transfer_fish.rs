@@ -1,47 +1,41 @@
 use crate::errors::ErrorCode;
+use crate::instructions::common::move_profile_fish;
 use crate::instructions::TransferFish;
 use anchor_lang::prelude::*;
 
-/// Moves a fish account to a new owner after validating ownership, liveliness,
-/// and preventing self-transfers. Copies the existing fish state into the
-/// destination account and emits a transfer event.
+/// Hands a fish over to a new owner after validating ownership, liveliness and
+/// preventing self-transfers. The fish account keeps its address, so only the stored
+/// owner changes before the transfer event is emitted.
 pub fn handle(ctx: Context<TransferFish>) -> Result<()> {
-    let fish = &ctx.accounts.fish;
+    let fish = &mut ctx.accounts.fish;
+    let current_owner = &ctx.accounts.current_owner;
+    let new_owner = &ctx.accounts.new_owner;
 
-    fish.ensure_owned_by(&ctx.accounts.current_owner.key())?;
+    fish.ensure_owned_by(&current_owner.key())?;
     require!(
-        ctx.accounts.current_owner.key() != ctx.accounts.new_owner.key(),
+        current_owner.key() != new_owner.key(),
         ErrorCode::CannotTransferToSelf
     );
     fish.ensure_alive()?;
+    fish.ensure_not_trade_locked()?;
 
-    let new_fish = &mut ctx.accounts.new_fish;
-    // Full state copy (except owner which is set to the new owner)
-    new_fish.id = fish.id;
-    new_fish.owner = ctx.accounts.new_owner.key();
-    new_fish.name = fish.name.clone();
-    new_fish.share = fish.share;
-    new_fish.created_at = fish.created_at;
-    new_fish.last_fed_at = fish.last_fed_at;
-    new_fish.last_hunt_at = fish.last_hunt_at;
-    new_fish.can_hunt_after = fish.can_hunt_after;
-    new_fish.is_protected = fish.is_protected;
-    new_fish.protection_ends_at = fish.protection_ends_at;
-    new_fish.total_hunts = fish.total_hunts;
-    new_fish.total_hunt_income = fish.total_hunt_income;
-    new_fish.received_from_hunt_value = fish.received_from_hunt_value;
-    new_fish.hunting_marks_placed = fish.hunting_marks_placed;
-    new_fish.last_mark_reset = fish.last_mark_reset;
-    new_fish.marked_by_hunter_id = fish.marked_by_hunter_id;
-    new_fish.mark_placed_at = fish.mark_placed_at;
-    new_fish.mark_expires_at = fish.mark_expires_at;
-    new_fish.mark_cost = fish.mark_cost;
+    fish.owner = new_owner.key();
+    // Only a receiver who signs has the fish listed, so nobody can fill another
+    // player's profile with unwanted fish.
+    let to_profile = if new_owner.is_signer {
+        ctx.accounts.to_profile.as_mut()
+    } else {
+        None
+    };
+    move_profile_fish(ctx.accounts.from_profile.as_mut(), to_profile, fish.id);
+    if let Some(leaderboard) = ctx.accounts.leaderboard.as_mut() {
+        leaderboard.record(fish, Clock::get()?.unix_timestamp);
+    }
 
-    // Emit transfer event
     emit!(crate::FishTransferred {
-        fish_id: new_fish.id,
-        from_owner: ctx.accounts.current_owner.key(),
-        to_owner: ctx.accounts.new_owner.key(),
+        fish_id: fish.id,
+        from_owner: current_owner.key(),
+        to_owner: new_owner.key(),
     });
 
     Ok(())
@@ -0,0 +1,26 @@
+use crate::errors::ErrorCode;
+use crate::state::AutoFeed;
+use crate::{events::*, instructions::WithdrawAutoFeed, utils::*};
+use anchor_lang::prelude::*;
+
+/// Returns part of the unspent auto-feed budget to the owner, leaving the escrow open.
+pub fn handle(ctx: Context<WithdrawAutoFeed>, amount: u64) -> Result<()> {
+    let auto_feed = &ctx.accounts.auto_feed;
+    let owner = &ctx.accounts.owner;
+
+    let escrow = auto_feed.to_account_info();
+    require!(
+        AutoFeed::available(&escrow)? >= amount,
+        ErrorCode::InsufficientEscrow
+    );
+    transfer_lamports(&escrow, &owner.to_account_info(), amount)?;
+
+    emit!(AutoFeedWithdrawn {
+        fish_id: auto_feed.fish_id,
+        owner: owner.key(),
+        amount,
+        closed: false,
+    });
+
+    Ok(())
+}
@@ -5,6 +5,8 @@ declare_id!("B1osUCap5eJ2iJnbRqfCQB87orhJM5EqZqPcGMbjJvXz");
 
 pub mod events;
 pub use events::*;
+pub mod views;
+pub use views::*;
 pub mod errors;
 pub mod utils;
 pub use utils::*;
@@ -32,6 +34,110 @@ pub mod cryptofish {
         instructions::feed_fish::handle(ctx, feeding_amount)
     }
 
+    pub fn feed_many<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FeedMany<'info>>,
+        amounts: Vec<u64>,
+        skip_failed: bool,
+    ) -> Result<FeedManyResult> {
+        instructions::feed_many::handle(ctx, amounts, skip_failed)
+    }
+
+    pub fn register_referrer(ctx: Context<RegisterReferrer>) -> Result<()> {
+        instructions::register_referrer::handle(ctx)
+    }
+
+    pub fn claim_referral_earnings(ctx: Context<ClaimReferralEarnings>) -> Result<()> {
+        instructions::claim_referral_earnings::handle(ctx)
+    }
+
+    pub fn initialize_achievements(ctx: Context<InitializeAchievements>) -> Result<()> {
+        instructions::initialize_achievements::handle(ctx)
+    }
+
+    pub fn create_profile(ctx: Context<CreateProfile>, handle: String) -> Result<()> {
+        instructions::create_profile::handle(ctx, handle)
+    }
+
+    pub fn set_profile_handle(ctx: Context<SetProfileHandle>, handle: String) -> Result<()> {
+        instructions::set_profile_handle::handle(ctx, handle)
+    }
+
+    pub fn sync_profile<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SyncProfile<'info>>,
+    ) -> Result<()> {
+        instructions::sync_profile::handle(ctx)
+    }
+
+    pub fn open_season(
+        ctx: Context<OpenSeason>,
+        season_id: u64,
+        start_time: i64,
+        end_time: i64,
+        pot_fee_bps: u64,
+    ) -> Result<()> {
+        instructions::open_season::handle(ctx, season_id, start_time, end_time, pot_fee_bps)
+    }
+
+    pub fn finalize_season<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FinalizeSeason<'info>>,
+    ) -> Result<()> {
+        instructions::finalize_season::handle(ctx)
+    }
+
+    pub fn claim_season_prize(ctx: Context<ClaimSeasonPrize>, rank: u8) -> Result<()> {
+        instructions::claim_season_prize::handle(ctx, rank)
+    }
+
+    pub fn close_season(ctx: Context<CloseSeason>) -> Result<()> {
+        instructions::close_season::handle(ctx)
+    }
+
+    pub fn buy_shield(ctx: Context<BuyShield>, duration: i64) -> Result<()> {
+        instructions::buy_shield::handle(ctx, duration)
+    }
+
+    pub fn grant_delegation(
+        ctx: Context<GrantDelegation>,
+        delegate: Pubkey,
+        permissions: u8,
+        expires_at: i64,
+        spend_cap: u64,
+    ) -> Result<()> {
+        instructions::grant_delegation::handle(ctx, delegate, permissions, expires_at, spend_cap)
+    }
+
+    pub fn revoke_delegation(ctx: Context<RevokeDelegation>) -> Result<()> {
+        instructions::revoke_delegation::handle(ctx)
+    }
+
+    pub fn create_session(ctx: Context<CreateSession>, duration: i64, budget: u64) -> Result<()> {
+        instructions::create_session::handle(ctx, duration, budget)
+    }
+
+    pub fn revoke_session(ctx: Context<RevokeSession>) -> Result<()> {
+        instructions::revoke_session::handle(ctx)
+    }
+
+    pub fn fund_auto_feed(
+        ctx: Context<FundAutoFeed>,
+        amount: u64,
+        max_feed_amount: u64,
+    ) -> Result<()> {
+        instructions::fund_auto_feed::handle(ctx, amount, max_feed_amount)
+    }
+
+    pub fn execute_auto_feed(ctx: Context<ExecuteAutoFeed>) -> Result<()> {
+        instructions::execute_auto_feed::handle(ctx)
+    }
+
+    pub fn withdraw_auto_feed(ctx: Context<WithdrawAutoFeed>, amount: u64) -> Result<()> {
+        instructions::withdraw_auto_feed::handle(ctx, amount)
+    }
+
+    pub fn close_auto_feed(ctx: Context<CloseAutoFeed>) -> Result<()> {
+        instructions::close_auto_feed::handle(ctx)
+    }
+
     pub fn hunt_fish(ctx: Context<HuntFish>, expected_prey_share: u64) -> Result<()> {
         instructions::hunt_fish::handle(ctx, expected_prey_share)
     }
@@ -40,14 +146,180 @@ pub mod cryptofish {
         instructions::exit_game::handle(ctx)
     }
 
+    pub fn reap_starved_fish<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReapStarvedFish<'info>>,
+    ) -> Result<()> {
+        instructions::reap_starved_fish::handle(ctx)
+    }
+
     pub fn get_fish_info(ctx: Context<GetFishInfo>) -> Result<()> {
         instructions::get_fish_info::handle(ctx)
     }
 
+    pub fn get_fish_snapshot(ctx: Context<GetFishSnapshot>) -> Result<FishSnapshot> {
+        instructions::get_fish_snapshot::handle(ctx)
+    }
+
+    pub fn get_ocean_snapshot(ctx: Context<GetOceanSnapshot>) -> Result<OceanSnapshot> {
+        instructions::get_ocean_snapshot::handle(ctx)
+    }
+
+    pub fn initialize_leaderboard(ctx: Context<InitializeLeaderboard>) -> Result<()> {
+        instructions::initialize_leaderboard::handle(ctx)
+    }
+
+    pub fn get_leaderboard(ctx: Context<GetLeaderboard>) -> Result<LeaderboardSnapshot> {
+        instructions::get_leaderboard::handle(ctx)
+    }
+
+    pub fn prune_leaderboard<'info>(
+        ctx: Context<'_, '_, 'info, 'info, PruneLeaderboard<'info>>,
+        fish_ids: Vec<u64>,
+    ) -> Result<()> {
+        instructions::prune_leaderboard::handle(ctx, fish_ids)
+    }
+
+    pub fn quote_feed(ctx: Context<QuoteFeed>, feeding_amount: u64) -> Result<FeedQuote> {
+        instructions::quote_feed::handle(ctx, feeding_amount)
+    }
+
+    pub fn quote_create(
+        ctx: Context<QuoteCreate>,
+        deposit: u64,
+        is_resurrection: bool,
+    ) -> Result<CreateQuote> {
+        instructions::quote_create::handle(ctx, deposit, is_resurrection)
+    }
+
+    pub fn quote_exit(ctx: Context<QuoteExit>) -> Result<ExitQuote> {
+        instructions::quote_exit::handle(ctx)
+    }
+
+    pub fn quote_mark(ctx: Context<QuoteMark>) -> Result<MarkQuote> {
+        instructions::quote_mark::handle(ctx)
+    }
+
+    pub fn quote_shield(ctx: Context<QuoteShield>, duration: i64) -> Result<ShieldQuote> {
+        instructions::quote_shield::handle(ctx, duration)
+    }
+
+    pub fn quote_hunt(ctx: Context<QuoteHunt>) -> Result<HuntQuote> {
+        instructions::quote_hunt::handle(ctx)
+    }
+
+    pub fn check_hunt(ctx: Context<CheckHunt>, expected_prey_share: u64) -> Result<HuntCheck> {
+        instructions::check_hunt::handle(ctx, expected_prey_share)
+    }
+
+    pub fn create_hunt_intent(
+        ctx: Context<CreateHuntIntent>,
+        prey_id: u64,
+        expected_prey_share: u64,
+        max_prey_share: u64,
+        max_slippage_bps: u64,
+        deadline: i64,
+        tip: u64,
+    ) -> Result<()> {
+        instructions::create_hunt_intent::handle(
+            ctx,
+            prey_id,
+            expected_prey_share,
+            max_prey_share,
+            max_slippage_bps,
+            deadline,
+            tip,
+        )
+    }
+
+    pub fn cancel_hunt_intent(ctx: Context<CancelHuntIntent>) -> Result<()> {
+        instructions::cancel_hunt_intent::handle(ctx)
+    }
+
+    pub fn execute_hunt_intent(ctx: Context<ExecuteHuntIntent>) -> Result<()> {
+        instructions::execute_hunt_intent::handle(ctx)
+    }
+
     pub fn transfer_fish(ctx: Context<TransferFish>) -> Result<()> {
         instructions::transfer_fish::handle(ctx)
     }
 
+    pub fn merge_fish(ctx: Context<MergeFish>) -> Result<()> {
+        instructions::merge_fish::handle(ctx)
+    }
+
+    pub fn split_fish(ctx: Context<SplitFish>, name: String, split_bps: u64) -> Result<()> {
+        instructions::split_fish::handle(ctx, name, split_bps)
+    }
+
+    pub fn create_transfer_offer(
+        ctx: Context<CreateTransferOffer>,
+        recipient: Pubkey,
+        price: u64,
+        expires_at: Option<i64>,
+    ) -> Result<()> {
+        instructions::create_transfer_offer::handle(ctx, recipient, price, expires_at)
+    }
+
+    pub fn accept_transfer_offer(
+        ctx: Context<AcceptTransferOffer>,
+        max_price: u64,
+    ) -> Result<()> {
+        instructions::accept_transfer_offer::handle(ctx, max_price)
+    }
+
+    pub fn cancel_transfer_offer(ctx: Context<CancelTransferOffer>) -> Result<()> {
+        instructions::cancel_transfer_offer::handle(ctx)
+    }
+
+    pub fn list_fish(
+        ctx: Context<ListFish>,
+        price: u64,
+        auction_ends_at: Option<i64>,
+    ) -> Result<()> {
+        instructions::list_fish::handle(ctx, price, auction_ends_at)
+    }
+
+    pub fn cancel_listing<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CancelListing<'info>>,
+    ) -> Result<()> {
+        instructions::cancel_listing::handle(ctx)
+    }
+
+    pub fn close_listing<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseListing<'info>>,
+    ) -> Result<()> {
+        instructions::close_listing::handle(ctx)
+    }
+
+    pub fn place_bid(ctx: Context<PlaceBid>, amount: u64) -> Result<()> {
+        instructions::place_bid::handle(ctx, amount)
+    }
+
+    pub fn cancel_bid(ctx: Context<CancelBid>) -> Result<()> {
+        instructions::cancel_bid::handle(ctx)
+    }
+
+    pub fn buy_listed_fish<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BuyListedFish<'info>>,
+        max_price: u64,
+    ) -> Result<()> {
+        instructions::buy_listed_fish::handle(ctx, max_price)
+    }
+
+    pub fn accept_bid<'info>(ctx: Context<'_, '_, 'info, 'info, AcceptBid<'info>>) -> Result<()> {
+        instructions::accept_bid::handle(ctx)
+    }
+
+    pub fn settle_auction<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SettleAuction<'info>>,
+    ) -> Result<()> {
+        instructions::settle_auction::handle(ctx)
+    }
+
+    pub fn migrate_fish(ctx: Context<MigrateFish>, fish_id: u64) -> Result<()> {
+        instructions::migrate_fish::handle(ctx, fish_id)
+    }
+
     pub fn get_share_value(ctx: Context<GetShareValue>) -> Result<u64> {
         instructions::get_share_value::handle(ctx)
     }
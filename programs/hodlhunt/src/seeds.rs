@@ -5,14 +5,33 @@ pub const SEED_OCEAN: &[u8] = b"ocean";
 pub const SEED_VAULT: &[u8] = b"vault";
 pub const SEED_FISH: &[u8] = b"fish";
 pub const SEED_NAME: &[u8] = b"fish_name";
+pub const SEED_TRANSFER_OFFER: &[u8] = b"transfer_offer";
+pub const SEED_LISTING: &[u8] = b"listing";
+pub const SEED_BID: &[u8] = b"bid";
+pub const SEED_AUTO_FEED: &[u8] = b"auto_feed";
+pub const SEED_DELEGATION: &[u8] = b"delegation";
+pub const SEED_SESSION: &[u8] = b"session";
+pub const SEED_HUNT_INTENT: &[u8] = b"hunt_intent";
+pub const SEED_REFERRAL: &[u8] = b"referral";
+pub const SEED_SEASON: &[u8] = b"season";
+pub const SEED_SEASON_SCHEDULE: &[u8] = b"season_schedule";
+pub const SEED_LEADERBOARD: &[u8] = b"leaderboard";
+pub const SEED_ACHIEVEMENTS: &[u8] = b"achievements";
+pub const SEED_PROFILE: &[u8] = b"profile";
 
 /// Derives the vault PDA associated with the provided ocean account.
 pub fn derive_vault_pda(program_id: &Pubkey, ocean: &Pubkey) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[SEED_VAULT, ocean.as_ref()], program_id)
 }
 
-/// Derives the fish PDA for the specified owner and fish identifier.
-pub fn derive_fish_pda(program_id: &Pubkey, owner: &Pubkey, fish_id: u64) -> (Pubkey, u8) {
+/// Derives the fish PDA for the specified ocean and fish identifier. The owner is kept
+/// in account data so the address stays stable across transfers.
+pub fn derive_fish_pda(program_id: &Pubkey, ocean: &Pubkey, fish_id: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[SEED_FISH, ocean.as_ref(), &fish_id.to_le_bytes()], program_id)
+}
+
+/// Derives the pre-migration fish PDA that was keyed by owner instead of ocean.
+pub fn derive_legacy_fish_pda(program_id: &Pubkey, owner: &Pubkey, fish_id: u64) -> (Pubkey, u8) {
     Pubkey::find_program_address(&[SEED_FISH, owner.as_ref(), &fish_id.to_le_bytes()], program_id)
 }
 
@@ -0,0 +1,38 @@
+use crate::constants::achievements::MAX_ACHIEVEMENTS;
+use anchor_lang::prelude::*;
+
+/// Per-owner record of unlocked achievements: a bitset plus the time each bit was set.
+#[account]
+#[derive(Default)]
+pub struct Achievements {
+    pub owner: Pubkey,
+    pub unlocked: u16,
+    pub unlocked_at: [i64; MAX_ACHIEVEMENTS],
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl Achievements {
+    pub const INIT_SPACE: usize = 32 + 2 + 8 * MAX_ACHIEVEMENTS + 8 + 1;
+
+    pub const FIRST_HUNT: u8 = 0;
+    pub const TEN_HUNTS: u8 = 1;
+    pub const STORM_SURVIVOR: u8 = 2;
+    pub const TEN_SOL_VALUE: u8 = 3;
+    pub const RESURRECTED: u8 = 4;
+
+    /// Returns true when `achievement` has been unlocked.
+    pub fn has(&self, achievement: u8) -> bool {
+        self.unlocked & (1 << achievement) != 0
+    }
+
+    /// Unlocks `achievement` at `current_time`. Returns false if it was already unlocked.
+    pub fn unlock(&mut self, achievement: u8, current_time: i64) -> bool {
+        if self.has(achievement) {
+            return false;
+        }
+        self.unlocked |= 1 << achievement;
+        self.unlocked_at[achievement as usize] = current_time;
+        true
+    }
+}
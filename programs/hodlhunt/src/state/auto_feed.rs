@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Default)]
+pub struct AutoFeed {
+    pub fish: Pubkey,
+    pub fish_id: u64,
+    pub owner: Pubkey,
+    /// Upper bound on a single keeper feeding; zero means uncapped.
+    pub max_feed_amount: u64,
+    pub total_deposited: u64,
+    pub total_spent: u64,
+    pub executions: u64,
+    pub last_executed_at: i64,
+    pub bump: u8,
+}
+
+impl AutoFeed {
+    pub const INIT_SPACE: usize = 32 + 8 + 32 + 8 + 8 + 8 + 8 + 8 + 1;
+
+    /// Returns the lamports held by the escrow above its rent-exempt minimum.
+    pub fn available(info: &AccountInfo) -> Result<u64> {
+        let rent = Rent::get()?.minimum_balance(info.data_len());
+        Ok(info.lamports().saturating_sub(rent))
+    }
+}
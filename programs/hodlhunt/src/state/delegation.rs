@@ -0,0 +1,46 @@
+use crate::errors::ErrorCode;
+use anchor_lang::prelude::*;
+
+/// Grants a delegate key scoped authority over all fish of an owner. Delegates may
+/// feed, hunt and mark within the granted permissions, expiry and spending cap, but
+/// can never exit, transfer or trade a fish.
+#[account]
+#[derive(Default)]
+pub struct Delegation {
+    pub owner: Pubkey,
+    pub delegate: Pubkey,
+    pub permissions: u8,
+    pub expires_at: i64,
+    /// Maximum lamports the delegate may spend on the owner's fish
+    pub spend_cap: u64,
+    pub spent: u64,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl Delegation {
+    pub const INIT_SPACE: usize = 32 + 32 + 1 + 8 + 8 + 8 + 8 + 1;
+
+    pub const PERMISSION_FEED: u8 = 1 << 0;
+    pub const PERMISSION_HUNT: u8 = 1 << 1;
+    pub const PERMISSION_MARK: u8 = 1 << 2;
+    pub const PERMISSION_ALL: u8 =
+        Self::PERMISSION_FEED | Self::PERMISSION_HUNT | Self::PERMISSION_MARK;
+
+    /// Checks that the grant is live and covers `permission`, then records `spend`
+    /// against the cap.
+    pub fn authorize(&mut self, permission: u8, spend: u64, current_time: i64) -> Result<()> {
+        require!(current_time < self.expires_at, ErrorCode::DelegationExpired);
+        require!(
+            self.permissions & permission == permission,
+            ErrorCode::DelegationPermissionDenied
+        );
+        let spent = self
+            .spent
+            .checked_add(spend)
+            .ok_or(ErrorCode::MathOverflow)?;
+        require!(spent <= self.spend_cap, ErrorCode::DelegationCapExceeded);
+        self.spent = spent;
+        Ok(())
+    }
+}
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::constants::{marks, ocean};
+use crate::constants::{marks, ocean, shields, streaks};
 
 use crate::errors::ErrorCode;
 
@@ -26,18 +26,40 @@ pub struct Fish {
     pub mark_placed_at: i64,
     pub mark_expires_at: i64,
     pub mark_cost: u64,
+    pub trade_lock: u8,
+    pub parent_fish_id: u64,
+    pub generation: u32,
+    pub resurrected_into_id: u64,
+    pub last_shield_at: i64,
+    pub shield_season: u64,
+    pub shield_seconds_used: i64,
+    pub feeding_streak: u32,
+    pub best_feeding_streak: u32,
+    pub season_id: u64,
+    pub season_start_value: u64,
+    pub season_last_value: u64,
+    pub season_contributed: u64,
+    pub season_hunts: u32,
+    pub season_hunt_income: u64,
+    pub season_ends_at: i64,
+    pub season_withdrawn: u64,
 }
 
 impl Fish {
-    pub const INIT_SPACE: usize = 190;
+    pub const INIT_SPACE: usize = 303;
 
     pub const PROTECTION_PERIOD: i64 = 7 * ocean::DAY_DURATION;
     pub const CREATION_HUNTING_COOLDOWN: i64 = 2 * ocean::DAY_DURATION;
     pub const POST_HUNT_COOLDOWN: i64 = 2 * ocean::DAY_DURATION;
     pub const PREY_COOLDOWN: i64 = 7 * ocean::DAY_DURATION;
     pub const FEEDING_COOLDOWN: i64 = 2 * ocean::DAY_DURATION;
+    pub const STARVATION_PERIOD: i64 = 14 * ocean::DAY_DURATION;
     pub const MARK_EXCLUSIVITY_PERIOD: i64 = marks::EXCLUSIVITY_SECONDS;
 
+    pub const TRADE_LOCK_NONE: u8 = 0;
+    pub const TRADE_LOCK_OFFER: u8 = 1;
+    pub const TRADE_LOCK_LISTING: u8 = 2;
+
     /// Returns true when the fish can initiate a hunt at the provided timestamp.
     pub fn can_hunt(&self, current_time: i64) -> bool {
         current_time >= self.can_hunt_after && self.share > 0
@@ -58,6 +80,13 @@ impl Fish {
         }
     }
 
+    /// Ensures no unexpired hunting mark is placed on the fish.
+    pub fn ensure_unmarked(&mut self, current_time: i64) -> Result<()> {
+        self.clear_expired_mark(current_time);
+        require!(self.marked_by_hunter_id == 0, ErrorCode::FishMarked);
+        Ok(())
+    }
+
     /// Verifies that the fish is a valid prey candidate at the given timestamp.
     pub fn is_valid_prey(&self, current_time: i64) -> bool {
         if self.share == 0 {
@@ -73,6 +102,115 @@ impl Fish {
         true
     }
 
+    /// Clears `is_protected` once the protection window has lapsed and returns whether the
+    /// fish is still protected at `current_time`.
+    pub fn refresh_protection(&mut self, current_time: i64) -> bool {
+        if self.is_protected && current_time >= self.protection_ends_at {
+            self.is_protected = false;
+        }
+        self.is_protected
+    }
+
+    /// Returns the shield season index that contains `current_time`.
+    pub fn shield_season_at(current_time: i64) -> u64 {
+        (current_time.max(0) / shields::SEASON_SECONDS) as u64
+    }
+
+    /// Returns the seconds of shield already bought in the season containing `current_time`.
+    pub fn shield_seconds_in_season(&self, current_time: i64) -> i64 {
+        if self.shield_season == Self::shield_season_at(current_time) {
+            self.shield_seconds_used
+        } else {
+            0
+        }
+    }
+
+    /// Returns the timestamp at which the fish may buy its next shield.
+    pub fn shield_available_at(&self) -> i64 {
+        if self.last_shield_at > 0 {
+            self.protection_ends_at + shields::COOLDOWN_SECONDS
+        } else if self.is_protected {
+            self.protection_ends_at
+        } else {
+            0
+        }
+    }
+
+    /// Returns the time from which a feeding extends the streak.
+    pub fn streak_window_opens_at(&self) -> i64 {
+        self.last_fed_at + Self::PREY_COOLDOWN - streaks::WINDOW_SECONDS
+    }
+
+    /// Returns the feeding streak after a feeding at `current_time`: extended inside the
+    /// window before hunger, unchanged for an early feeding and reset once hungry.
+    pub fn next_feeding_streak(&self, current_time: i64) -> u32 {
+        if current_time >= self.last_fed_at + Self::PREY_COOLDOWN {
+            0
+        } else if current_time >= self.streak_window_opens_at() {
+            self.feeding_streak.saturating_add(1)
+        } else {
+            self.feeding_streak
+        }
+    }
+
+    /// Enters the fish into `season_id`, which ends at `ends_at`, with `value_before` as
+    /// its baseline. A fish already in that season keeps its stats.
+    pub fn enter_season(&mut self, season_id: u64, ends_at: i64, value_before: u64) {
+        if self.season_id == season_id {
+            return;
+        }
+        self.season_id = season_id;
+        self.season_ends_at = ends_at;
+        self.season_start_value = value_before;
+        self.season_last_value = value_before;
+        self.season_contributed = 0;
+        self.season_withdrawn = 0;
+        self.season_hunts = 0;
+        self.season_hunt_income = 0;
+    }
+
+    /// Records a value change while the fish's season is running, whether or not the
+    /// handler was given the season account. `contributed` and `withdrawn` are the
+    /// lamports the owner moved in or out. Returns whether the change counted.
+    pub fn record_season_activity(
+        &mut self,
+        value_after: u64,
+        contributed: u64,
+        withdrawn: u64,
+        current_time: i64,
+    ) -> bool {
+        if self.season_id == 0 || current_time >= self.season_ends_at {
+            return false;
+        }
+        self.season_contributed = self.season_contributed.saturating_add(contributed);
+        self.season_withdrawn = self.season_withdrawn.saturating_add(withdrawn);
+        self.season_last_value = value_after;
+        true
+    }
+
+    /// Counts a successful hunt towards the current season.
+    pub fn record_season_hunt(&mut self, income: u64) {
+        self.season_hunts = self.season_hunts.saturating_add(1);
+        self.season_hunt_income = self.season_hunt_income.saturating_add(income);
+    }
+
+    /// Returns the value gained in the current season beyond what the owner paid in.
+    pub fn season_score(&self) -> u64 {
+        self.season_last_value
+            .saturating_add(self.season_withdrawn)
+            .saturating_sub(self.season_start_value.saturating_add(self.season_contributed))
+    }
+
+    /// Returns the timestamp at which an unfed fish starts starving.
+    pub fn starves_at(&self) -> i64 {
+        self.last_fed_at + Self::STARVATION_PERIOD
+    }
+
+    /// Returns true when the fish is alive but has gone unfed past the starvation period.
+    pub fn is_starving(&self, current_time: i64) -> bool {
+        self.share > 0 && current_time >= self.starves_at()
+    }
+
     /// Ensures the fish has non-zero share and is considered alive.
     pub fn ensure_alive(&self) -> Result<()> {
         require!(self.share > 0, ErrorCode::FishAlreadyDead);
@@ -85,9 +223,52 @@ impl Fish {
         Ok(())
     }
 
+    /// Ensures a dead fish has not already been resurrected into a successor.
+    pub fn ensure_not_resurrected(&self) -> Result<()> {
+        require!(
+            self.resurrected_into_id == 0,
+            ErrorCode::FishAlreadyResurrected
+        );
+        Ok(())
+    }
+
     /// Validates that the fish is owned by the provided public key.
     pub fn ensure_owned_by(&self, owner: &Pubkey) -> Result<()> {
         require!(self.owner == *owner, ErrorCode::NotFishOwner);
         Ok(())
     }
+
+    /// Ensures the fish is not frozen by an open transfer offer or marketplace listing.
+    pub fn ensure_not_trade_locked(&self) -> Result<()> {
+        require!(
+            self.trade_lock == Self::TRADE_LOCK_NONE,
+            ErrorCode::FishTradeLocked
+        );
+        Ok(())
+    }
+}
+
+/// Account layout of fish created before PDAs were keyed by ocean. Kept separately from
+/// [`Fish`] so migration can still decode old accounts after the live layout grows.
+#[derive(AnchorSerialize, AnchorDeserialize, Default)]
+pub struct LegacyFish {
+    pub id: u64,
+    pub owner: Pubkey,
+    pub share: u64,
+    pub name: String,
+    pub created_at: i64,
+    pub last_fed_at: i64,
+    pub last_hunt_at: i64,
+    pub can_hunt_after: i64,
+    pub is_protected: bool,
+    pub protection_ends_at: i64,
+    pub total_hunts: u64,
+    pub total_hunt_income: u64,
+    pub received_from_hunt_value: u64,
+    pub hunting_marks_placed: u8,
+    pub last_mark_reset: i64,
+    pub marked_by_hunter_id: u64,
+    pub mark_placed_at: i64,
+    pub mark_expires_at: i64,
+    pub mark_cost: u64,
 }
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+/// Standing order for a hunter fish that any keeper may execute before the deadline.
+/// The intent either names a prey or, with `prey_id == 0`, accepts any prey whose share
+/// does not exceed `max_prey_share`. The keeper tip is escrowed on top of rent.
+#[account]
+#[derive(Default)]
+pub struct HuntIntent {
+    pub hunter: Pubkey,
+    pub hunter_id: u64,
+    pub owner: Pubkey,
+    pub prey_id: u64,
+    /// Prey share the slippage bound is measured from when a prey is named
+    pub expected_prey_share: u64,
+    /// Upper bound on the prey share; zero means no bound
+    pub max_prey_share: u64,
+    pub max_slippage_bps: u64,
+    pub tip: u64,
+    pub deadline: i64,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl HuntIntent {
+    pub const INIT_SPACE: usize = 32 + 8 + 32 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 1;
+
+    /// Returns the prey share to check slippage against, or `None` when `prey_share`
+    /// (of the fish with `prey_id`) is not covered by this intent.
+    pub fn expected_share_for(&self, prey_id: u64, prey_share: u64) -> Option<u64> {
+        if self.max_prey_share > 0 && prey_share > self.max_prey_share {
+            return None;
+        }
+        if self.prey_id == 0 {
+            Some(prey_share)
+        } else if self.prey_id == prey_id {
+            Some(self.expected_prey_share)
+        } else {
+            None
+        }
+    }
+}
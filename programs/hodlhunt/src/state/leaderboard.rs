@@ -0,0 +1,194 @@
+use crate::constants::leaderboard::SIZE;
+use crate::state::Fish;
+use anchor_lang::prelude::*;
+
+/// A fish as listed on the leaderboard.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct LeaderboardEntry {
+    pub fish_id: u64,
+    pub owner: Pubkey,
+    pub share: u64,
+    pub total_hunt_income: u64,
+}
+
+impl LeaderboardEntry {
+    pub const INIT_SPACE: usize = 8 + 32 + 8 + 8;
+}
+
+/// Fixed-size top lists of the ocean's fish, kept sorted in descending order by share and
+/// by lifetime hunt income. Entries are refreshed whenever a handler touches the fish.
+#[account]
+#[derive(Default)]
+pub struct Leaderboard {
+    pub ocean: Pubkey,
+    pub by_share: [LeaderboardEntry; SIZE],
+    pub by_share_count: u8,
+    pub by_hunt_income: [LeaderboardEntry; SIZE],
+    pub by_hunt_income_count: u8,
+    pub updated_at: i64,
+    pub bump: u8,
+}
+
+impl Leaderboard {
+    pub const INIT_SPACE: usize = 32 + (LeaderboardEntry::INIT_SPACE * SIZE + 1) * 2 + 8 + 1;
+
+    /// Refreshes `fish` on both boards, dropping it once it is dead.
+    pub fn record(&mut self, fish: &Fish, current_time: i64) {
+        let entry = LeaderboardEntry {
+            fish_id: fish.id,
+            owner: fish.owner,
+            share: fish.share,
+            total_hunt_income: fish.total_hunt_income,
+        };
+        let alive = fish.share > 0;
+        update_board(
+            &mut self.by_share,
+            &mut self.by_share_count,
+            entry,
+            alive,
+            |entry| entry.share,
+        );
+        update_board(
+            &mut self.by_hunt_income,
+            &mut self.by_hunt_income_count,
+            entry,
+            alive && fish.total_hunt_income > 0,
+            |entry| entry.total_hunt_income,
+        );
+        self.updated_at = current_time;
+    }
+
+    /// Drops `fish_id` from both boards, for fish whose account no longer exists.
+    pub fn remove(&mut self, fish_id: u64, current_time: i64) {
+        let entry = LeaderboardEntry {
+            fish_id,
+            ..LeaderboardEntry::default()
+        };
+        update_board(
+            &mut self.by_share,
+            &mut self.by_share_count,
+            entry,
+            false,
+            |entry| entry.share,
+        );
+        update_board(
+            &mut self.by_hunt_income,
+            &mut self.by_hunt_income_count,
+            entry,
+            false,
+            |entry| entry.total_hunt_income,
+        );
+        self.updated_at = current_time;
+    }
+}
+
+/// Removes any entry for the fish, then inserts it at its sorted position when `listed`
+/// and it beats the last entry of a full board. Linear in the board size.
+fn update_board(
+    board: &mut [LeaderboardEntry; SIZE],
+    count: &mut u8,
+    entry: LeaderboardEntry,
+    listed: bool,
+    key: fn(&LeaderboardEntry) -> u64,
+) {
+    let mut len = *count as usize;
+    if let Some(index) = board[..len]
+        .iter()
+        .position(|listed| listed.fish_id == entry.fish_id)
+    {
+        board.copy_within(index + 1..len, index);
+        len -= 1;
+        board[len] = LeaderboardEntry::default();
+    }
+
+    if listed {
+        let position = board[..len]
+            .iter()
+            .position(|listed| key(listed) < key(&entry))
+            .unwrap_or(len);
+        if position < SIZE {
+            let end = (len + 1).min(SIZE);
+            board.copy_within(position..end - 1, position + 1);
+            board[position] = entry;
+            len = end;
+        }
+    }
+
+    *count = len as u8;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fish(id: u64, share: u64, total_hunt_income: u64) -> Fish {
+        Fish {
+            id,
+            share,
+            total_hunt_income,
+            ..Fish::default()
+        }
+    }
+
+    fn share_ids(leaderboard: &Leaderboard) -> Vec<u64> {
+        leaderboard.by_share[..leaderboard.by_share_count as usize]
+            .iter()
+            .map(|entry| entry.fish_id)
+            .collect()
+    }
+
+    fn income_ids(leaderboard: &Leaderboard) -> Vec<u64> {
+        leaderboard.by_hunt_income[..leaderboard.by_hunt_income_count as usize]
+            .iter()
+            .map(|entry| entry.fish_id)
+            .collect()
+    }
+
+    #[test]
+    fn record_inserts_sorted_and_reranks() {
+        let mut leaderboard = Leaderboard::default();
+        leaderboard.record(&fish(1, 100, 0), 1);
+        leaderboard.record(&fish(2, 300, 5), 2);
+        leaderboard.record(&fish(3, 200, 9), 3);
+        assert_eq!(share_ids(&leaderboard), vec![2, 3, 1]);
+        assert_eq!(income_ids(&leaderboard), vec![3, 2]);
+
+        leaderboard.record(&fish(1, 400, 0), 4);
+        assert_eq!(share_ids(&leaderboard), vec![1, 2, 3]);
+        assert_eq!(leaderboard.updated_at, 4);
+    }
+
+    #[test]
+    fn record_evicts_the_smallest_from_a_full_board() {
+        let mut leaderboard = Leaderboard::default();
+        for id in 1..=SIZE as u64 {
+            leaderboard.record(&fish(id, id * 10, 0), 0);
+        }
+        leaderboard.record(&fish(99, 5, 0), 0);
+        assert!(!share_ids(&leaderboard).contains(&99));
+
+        leaderboard.record(&fish(100, 15, 0), 0);
+        let ids = share_ids(&leaderboard);
+        assert_eq!(ids.len(), SIZE);
+        assert!(ids.contains(&100));
+        assert!(!ids.contains(&1));
+    }
+
+    #[test]
+    fn dead_or_removed_fish_leave_both_boards() {
+        let mut leaderboard = Leaderboard::default();
+        leaderboard.record(&fish(1, 100, 10), 0);
+        leaderboard.record(&fish(2, 200, 20), 0);
+        leaderboard.record(&fish(3, 300, 30), 0);
+
+        leaderboard.record(&fish(2, 0, 20), 0);
+        assert_eq!(share_ids(&leaderboard), vec![3, 1]);
+        assert_eq!(income_ids(&leaderboard), vec![3, 1]);
+
+        leaderboard.remove(3, 7);
+        assert_eq!(share_ids(&leaderboard), vec![1]);
+        assert_eq!(income_ids(&leaderboard), vec![1]);
+        assert_eq!(leaderboard.by_share[1].fish_id, 0);
+        assert_eq!(leaderboard.updated_at, 7);
+    }
+}
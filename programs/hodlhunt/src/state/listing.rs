@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+
+use crate::state::Fish;
+
+#[account]
+#[derive(Default)]
+pub struct Listing {
+    pub fish: Pubkey,
+    pub fish_id: u64,
+    pub seller: Pubkey,
+    /// Fixed sale price, or the minimum opening bid for auctions.
+    pub price: u64,
+    /// Zero for fixed-price listings.
+    pub auction_ends_at: i64,
+    pub highest_bid: u64,
+    pub highest_bidder: Pubkey,
+    pub open_bids: u32,
+    pub is_active: bool,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl Listing {
+    pub const INIT_SPACE: usize = 32 + 8 + 32 + 8 + 8 + 8 + 32 + 4 + 1 + 8 + 1;
+
+    /// Returns true when the listing is sold through an auction rather than at a fixed price.
+    pub fn is_auction(&self) -> bool {
+        self.auction_ends_at > 0
+    }
+
+    /// Returns true when the auction end time has been reached.
+    pub fn has_ended(&self, current_time: i64) -> bool {
+        self.is_auction() && current_time >= self.auction_ends_at
+    }
+
+    /// A listing is only live while the fish is alive, still owned by the seller and
+    /// locked by the listing. A fish eaten through `hunt_fish` invalidates it implicitly.
+    pub fn is_live(&self, fish: &Fish) -> bool {
+        self.is_active
+            && fish.share > 0
+            && fish.owner == self.seller
+            && fish.trade_lock == Fish::TRADE_LOCK_LISTING
+    }
+
+    /// Returns true when the price does not exceed what the buyer agreed to pay, so a
+    /// listing cancelled and re-created at a higher price cannot fill a stale purchase.
+    pub fn fits_max_price(&self, max_price: u64) -> bool {
+        self.price <= max_price
+    }
+}
+
+#[account]
+#[derive(Default)]
+pub struct Bid {
+    pub listing: Pubkey,
+    pub bidder: Pubkey,
+    /// Total lamports escrowed in the bid PDA on top of its rent.
+    pub amount: u64,
+    pub placed_at: i64,
+    pub bump: u8,
+}
+
+impl Bid {
+    pub const INIT_SPACE: usize = 32 + 32 + 8 + 8 + 1;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn listing(price: u64) -> Listing {
+        Listing {
+            price,
+            is_active: true,
+            ..Listing::default()
+        }
+    }
+
+    #[test]
+    fn buyer_max_price_covers_the_quoted_listing() {
+        assert!(listing(100).fits_max_price(100));
+        assert!(listing(90).fits_max_price(100));
+    }
+
+    #[test]
+    fn buyer_max_price_rejects_a_listing_re_listed_higher() {
+        let quoted = listing(100);
+        let max_price = quoted.price;
+        let relisted = listing(150);
+        assert!(!relisted.fits_max_price(max_price));
+    }
+}
@@ -1,5 +1,27 @@
+pub mod achievements;
+pub mod auto_feed;
+pub mod delegation;
 pub mod fish;
+pub mod hunt_intent;
+pub mod leaderboard;
+pub mod listing;
 pub mod ocean;
+pub mod player_profile;
+pub mod referral;
+pub mod season;
+pub mod session;
+pub mod transfer_offer;
 
-pub use fish::Fish;
+pub use achievements::Achievements;
+pub use auto_feed::AutoFeed;
+pub use delegation::Delegation;
+pub use fish::{Fish, LegacyFish};
+pub use hunt_intent::HuntIntent;
+pub use leaderboard::{Leaderboard, LeaderboardEntry};
+pub use listing::{Bid, Listing};
 pub use ocean::Ocean;
+pub use player_profile::PlayerProfile;
+pub use referral::Referral;
+pub use season::{Season, SeasonSchedule, SeasonWinner};
+pub use session::SessionKey;
+pub use transfer_offer::TransferOffer;
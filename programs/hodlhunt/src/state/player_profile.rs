@@ -0,0 +1,113 @@
+use crate::constants::profiles::{MAX_FISH, MAX_HANDLE_LEN};
+use crate::errors::ErrorCode;
+use anchor_lang::prelude::*;
+
+/// Per-wallet aggregate of a player's fish and lifetime activity, so clients can find a
+/// player's fish without scanning every fish account.
+#[account]
+#[derive(Default)]
+pub struct PlayerProfile {
+    pub owner: Pubkey,
+    pub handle: String,
+    /// Ids of the living fish the player owns
+    pub fish_ids: Vec<u64>,
+    /// Lamports put into fish through deposits and feedings, excluding fees
+    pub lifetime_deposits: u64,
+    /// Lamports paid out to the player on exit
+    pub lifetime_withdrawals: u64,
+    pub hunts: u64,
+    /// Fish lost to hunters or starvation
+    pub losses: u64,
+    /// Referrer bound when the profile was opened; default when the player was not referred
+    pub referrer: Pubkey,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl PlayerProfile {
+    pub const INIT_SPACE: usize =
+        32 + (4 + MAX_HANDLE_LEN) + (4 + 8 * MAX_FISH) + 8 * 4 + 32 + 8 + 1;
+
+    /// Trims and validates a display handle using the fish name rules.
+    pub fn validate_handle(handle: &str) -> Result<&str> {
+        let trimmed = handle.trim();
+        require!(
+            !trimmed.is_empty()
+                && trimmed.len() <= MAX_HANDLE_LEN
+                && trimmed.chars().all(|c| c.is_ascii() && !c.is_control()),
+            ErrorCode::InvalidHandle
+        );
+        Ok(trimmed)
+    }
+
+    /// Lists `fish_id` as owned by the player, failing when the list is full.
+    pub fn add_fish(&mut self, fish_id: u64) -> Result<()> {
+        require!(self.try_add_fish(fish_id), ErrorCode::ProfileFull);
+        Ok(())
+    }
+
+    /// Lists `fish_id` unless the list is full. Returns whether the fish is listed.
+    pub fn try_add_fish(&mut self, fish_id: u64) -> bool {
+        if self.fish_ids.contains(&fish_id) {
+            return true;
+        }
+        if self.fish_ids.len() >= MAX_FISH {
+            return false;
+        }
+        self.fish_ids.push(fish_id);
+        true
+    }
+
+    /// Drops `fish_id` from the player's fish.
+    pub fn remove_fish(&mut self, fish_id: u64) {
+        self.fish_ids.retain(|&id| id != fish_id);
+    }
+
+    /// Drops a fish the player lost and counts the loss.
+    pub fn record_loss(&mut self, fish_id: u64) {
+        self.remove_fish(fish_id);
+        self.losses = self.losses.saturating_add(1);
+    }
+
+    /// Adds `amount` to the lifetime deposits.
+    pub fn record_deposit(&mut self, amount: u64) {
+        self.lifetime_deposits = self.lifetime_deposits.saturating_add(amount);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn full_profile() -> PlayerProfile {
+        PlayerProfile {
+            fish_ids: (1..=MAX_FISH as u64).collect(),
+            ..PlayerProfile::default()
+        }
+    }
+
+    #[test]
+    fn add_fish_fails_at_capacity() {
+        let mut profile = full_profile();
+        assert!(profile.add_fish(MAX_FISH as u64 + 1).is_err());
+        assert_eq!(profile.fish_ids.len(), MAX_FISH);
+    }
+
+    #[test]
+    fn add_fish_accepts_listed_fish_at_capacity() {
+        let mut profile = full_profile();
+        assert!(profile.add_fish(1).is_ok());
+        assert_eq!(profile.fish_ids.len(), MAX_FISH);
+    }
+
+    #[test]
+    fn try_add_fish_skips_when_full() {
+        let mut profile = full_profile();
+        assert!(!profile.try_add_fish(MAX_FISH as u64 + 1));
+        assert!(!profile.fish_ids.contains(&(MAX_FISH as u64 + 1)));
+
+        profile.remove_fish(1);
+        assert!(profile.try_add_fish(MAX_FISH as u64 + 1));
+        assert_eq!(profile.fish_ids.len(), MAX_FISH);
+    }
+}
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+/// Per-referrer account that escrows referral cuts until they are claimed and keeps
+/// cumulative stats.
+#[account]
+#[derive(Default)]
+pub struct Referral {
+    pub referrer: Pubkey,
+    pub referred_actions: u64,
+    pub total_earned: u64,
+    pub total_claimed: u64,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl Referral {
+    pub const INIT_SPACE: usize = 32 + 8 + 8 + 8 + 8 + 1;
+
+    /// Returns the earnings not yet claimed.
+    pub fn claimable(&self) -> u64 {
+        self.total_earned.saturating_sub(self.total_claimed)
+    }
+}
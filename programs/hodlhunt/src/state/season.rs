@@ -0,0 +1,210 @@
+use crate::constants::{fees, seasons};
+use anchor_lang::prelude::*;
+
+/// A fish ranked in a season's final standings.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct SeasonWinner {
+    pub fish_id: u64,
+    pub owner: Pubkey,
+    pub score: u64,
+    pub hunts: u32,
+    pub hunt_income: u64,
+}
+
+impl SeasonWinner {
+    pub const INIT_SPACE: usize = 8 + 32 + 8 + 4 + 8;
+}
+
+/// Time-boxed competition funded by a slice of admin fees. Standings are built from
+/// candidates submitted after the season ends and paid out once finalization closes.
+#[account]
+#[derive(Default)]
+pub struct Season {
+    pub ocean: Pubkey,
+    pub season_id: u64,
+    pub start_time: i64,
+    pub end_time: i64,
+    pub pot_fee_bps: u64,
+    pub prize_pot: u64,
+    pub winners: [SeasonWinner; seasons::PRIZE_RANKS],
+    pub winner_count: u8,
+    pub claimed_mask: u8,
+    pub bump: u8,
+}
+
+impl Season {
+    pub const INIT_SPACE: usize =
+        32 + 8 + 8 + 8 + 8 + 8 + SeasonWinner::INIT_SPACE * seasons::PRIZE_RANKS + 1 + 1 + 1;
+
+    /// Returns true while actions count towards the season.
+    pub fn is_running(&self, current_time: i64) -> bool {
+        current_time >= self.start_time && current_time < self.end_time
+    }
+
+    /// Returns the time after which standings are frozen and prizes can be claimed.
+    pub fn finalization_closes_at(&self) -> i64 {
+        self.end_time + seasons::FINALIZE_WINDOW_SECONDS
+    }
+
+    /// Returns the time after which unclaimed prizes can be swept back to the admin.
+    pub fn claims_close_at(&self) -> i64 {
+        self.finalization_closes_at() + seasons::CLAIM_WINDOW_SECONDS
+    }
+
+    /// Returns the part of `admin_fee` routed into the prize pot.
+    pub fn pot_cut(&self, admin_fee: u64) -> u64 {
+        admin_fee * self.pot_fee_bps / fees::BASIS_POINTS_DIVISOR
+    }
+
+    /// Merges `candidate` into the standings, replacing an earlier entry for the same fish
+    /// and keeping the best scores in descending order. Returns whether it was ranked.
+    pub fn submit_candidate(&mut self, candidate: SeasonWinner) -> bool {
+        let count = self.winner_count as usize;
+        if let Some(index) = self.winners[..count]
+            .iter()
+            .position(|winner| winner.fish_id == candidate.fish_id)
+        {
+            self.winners[index] = candidate;
+        } else if count < seasons::PRIZE_RANKS {
+            self.winners[count] = candidate;
+            self.winner_count += 1;
+        } else if candidate.score > self.winners[count - 1].score {
+            self.winners[count - 1] = candidate;
+        } else {
+            return false;
+        }
+
+        let count = self.winner_count as usize;
+        self.winners[..count].sort_by_key(|winner| core::cmp::Reverse(winner.score));
+        true
+    }
+
+    /// Returns the prize for `rank`, splitting the pot over the ranks actually filled.
+    pub fn prize_for(&self, rank: usize) -> u64 {
+        let count = self.winner_count as usize;
+        if rank >= count {
+            return 0;
+        }
+        let total_bps: u64 = seasons::PRIZE_SPLIT_BPS[..count].iter().sum();
+        (self.prize_pot as u128 * seasons::PRIZE_SPLIT_BPS[rank] as u128 / total_bps as u128) as u64
+    }
+}
+
+/// Per-ocean record of the latest opened season, used to keep seasons from overlapping.
+/// A fish tracks one season at a time, so a new season may only start once the previous
+/// one has been finalized.
+#[account]
+#[derive(Default)]
+pub struct SeasonSchedule {
+    pub ocean: Pubkey,
+    pub last_season_id: u64,
+    pub last_finalization_closes_at: i64,
+    pub bump: u8,
+}
+
+impl SeasonSchedule {
+    pub const INIT_SPACE: usize = 32 + 8 + 8 + 1;
+
+    /// Returns true when `season` follows every season opened so far without overlap.
+    pub fn accepts(&self, season: &Season) -> bool {
+        season.season_id > self.last_season_id
+            && season.start_time >= self.last_finalization_closes_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(fish_id: u64, score: u64) -> SeasonWinner {
+        SeasonWinner {
+            fish_id,
+            score,
+            ..SeasonWinner::default()
+        }
+    }
+
+    fn ranked_ids(season: &Season) -> Vec<u64> {
+        season.winners[..season.winner_count as usize]
+            .iter()
+            .map(|winner| winner.fish_id)
+            .collect()
+    }
+
+    #[test]
+    fn submit_candidate_keeps_scores_sorted() {
+        let mut season = Season::default();
+        assert!(season.submit_candidate(candidate(1, 10)));
+        assert!(season.submit_candidate(candidate(2, 30)));
+        assert!(season.submit_candidate(candidate(3, 20)));
+        assert_eq!(ranked_ids(&season), vec![2, 3, 1]);
+    }
+
+    #[test]
+    fn submit_candidate_replaces_the_same_fish() {
+        let mut season = Season::default();
+        season.submit_candidate(candidate(1, 10));
+        season.submit_candidate(candidate(2, 20));
+        assert!(season.submit_candidate(candidate(1, 40)));
+        assert_eq!(ranked_ids(&season), vec![1, 2]);
+        assert_eq!(season.winners[0].score, 40);
+    }
+
+    #[test]
+    fn submit_candidate_evicts_the_lowest_when_full() {
+        let mut season = Season::default();
+        for (fish_id, score) in [(1, 10), (2, 20), (3, 30)] {
+            season.submit_candidate(candidate(fish_id, score));
+        }
+        assert!(!season.submit_candidate(candidate(4, 10)));
+        assert!(season.submit_candidate(candidate(5, 25)));
+        assert_eq!(ranked_ids(&season), vec![3, 5, 2]);
+    }
+
+    #[test]
+    fn prize_for_splits_over_filled_ranks() {
+        let mut season = Season {
+            prize_pot: 1_000,
+            ..Season::default()
+        };
+        assert_eq!(season.prize_for(0), 0);
+
+        season.submit_candidate(candidate(1, 10));
+        season.submit_candidate(candidate(2, 5));
+        assert_eq!(season.prize_for(0), 625);
+        assert_eq!(season.prize_for(1), 375);
+        assert_eq!(season.prize_for(2), 0);
+
+        season.submit_candidate(candidate(3, 1));
+        let paid: u64 = (0..seasons::PRIZE_RANKS)
+            .map(|rank| season.prize_for(rank))
+            .sum();
+        assert_eq!(paid, 1_000);
+    }
+
+    #[test]
+    fn schedule_rejects_overlapping_or_reused_seasons() {
+        let previous = Season {
+            season_id: 1,
+            start_time: 0,
+            end_time: 1_000,
+            ..Season::default()
+        };
+        let schedule = SeasonSchedule {
+            last_season_id: previous.season_id,
+            last_finalization_closes_at: previous.finalization_closes_at(),
+            ..SeasonSchedule::default()
+        };
+        let next = |season_id, start_time| Season {
+            season_id,
+            start_time,
+            end_time: start_time + 1_000,
+            ..Season::default()
+        };
+
+        assert!(!schedule.accepts(&next(2, 500)));
+        assert!(!schedule.accepts(&next(2, previous.finalization_closes_at() - 1)));
+        assert!(!schedule.accepts(&next(1, previous.finalization_closes_at())));
+        assert!(schedule.accepts(&next(2, previous.finalization_closes_at())));
+    }
+}
@@ -0,0 +1,33 @@
+use crate::errors::ErrorCode;
+use anchor_lang::prelude::*;
+
+/// Short-lived key a game client signs with instead of the owner wallet. Sessions may
+/// feed, hunt and mark on the owner's fish until they expire or their budget is spent.
+#[account]
+#[derive(Default)]
+pub struct SessionKey {
+    pub owner: Pubkey,
+    pub session_key: Pubkey,
+    pub expires_at: i64,
+    /// Lamports the owner handed to the session key; also the spending limit
+    pub budget: u64,
+    pub spent: u64,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+impl SessionKey {
+    pub const INIT_SPACE: usize = 32 + 32 + 8 + 8 + 8 + 8 + 1;
+
+    /// Checks that the session is live, then records `spend` against its budget.
+    pub fn authorize(&mut self, spend: u64, current_time: i64) -> Result<()> {
+        require!(current_time < self.expires_at, ErrorCode::SessionExpired);
+        let spent = self
+            .spent
+            .checked_add(spend)
+            .ok_or(ErrorCode::MathOverflow)?;
+        require!(spent <= self.budget, ErrorCode::SessionBudgetExceeded);
+        self.spent = spent;
+        Ok(())
+    }
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(Default)]
+pub struct TransferOffer {
+    pub fish: Pubkey,
+    pub fish_id: u64,
+    pub seller: Pubkey,
+    pub recipient: Pubkey,
+    pub price: u64,
+    pub created_at: i64,
+    pub expires_at: i64,
+    pub bump: u8,
+}
+
+impl TransferOffer {
+    pub const INIT_SPACE: usize = 32 + 8 + 32 + 32 + 8 + 8 + 8 + 1;
+
+    /// Returns true when the offer carries an expiry that has already passed.
+    pub fn is_expired(&self, current_time: i64) -> bool {
+        self.expires_at > 0 && current_time >= self.expires_at
+    }
+
+    /// Returns true when the price does not exceed what the recipient agreed to pay, so
+    /// an offer re-created at a higher price cannot be accepted by a stale transaction.
+    pub fn fits_max_price(&self, max_price: u64) -> bool {
+        self.price <= max_price
+    }
+}
@@ -0,0 +1,22 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak::{hash, hashv};
+use anchor_lang::solana_program::sysvar::slot_hashes::SlotHashes;
+use anchor_lang::solana_program::sysvar::Sysvar;
+
+/// Derives a pseudo-random seed by hashing `buf` together with the most recent slot hash,
+/// falling back to `buf` alone when `slot_hashes` is not a readable SlotHashes sysvar.
+pub fn entropy_seed(buf: &[u8], slot_hashes: &AccountInfo) -> u64 {
+    // Mix in recent blockhash (via SlotHashes sysvar from accounts)
+    let digest = if let Ok(slot_hashes) = SlotHashes::from_account_info(slot_hashes) {
+        if let Some((_, recent_hash)) = slot_hashes.slot_hashes().first() {
+            hashv(&[buf, recent_hash.as_ref()])
+        } else {
+            hash(buf)
+        }
+    } else {
+        hash(buf)
+    };
+    let mut seed_bytes = [0u8; 8];
+    seed_bytes.copy_from_slice(&digest.0[..8]);
+    u64::from_le_bytes(seed_bytes)
+}
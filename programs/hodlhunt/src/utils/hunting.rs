@@ -0,0 +1,310 @@
+use crate::constants::{fees, hunting, ocean};
+use crate::errors::ErrorCode;
+use crate::state::{Fish, Ocean};
+use crate::utils::entropy_seed;
+use crate::views::HuntCheck;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{get_stack_height, TRANSACTION_LEVEL_STACK_HEIGHT};
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+
+/// Returns the inclusive range of prey shares accepted for `expected_share` within
+/// `tolerance_bps` of slippage.
+pub fn slippage_bounds(expected_share: u64, tolerance_bps: u64) -> (u64, u64) {
+    let lower = expected_share
+        .saturating_mul(fees::BASIS_POINTS_DIVISOR.saturating_sub(tolerance_bps))
+        .saturating_div(fees::BASIS_POINTS_DIVISOR);
+    let upper = expected_share
+        .saturating_mul(fees::BASIS_POINTS_DIVISOR.saturating_add(tolerance_bps))
+        .saturating_div(fees::BASIS_POINTS_DIVISOR);
+    (lower, upper)
+}
+
+/// Returns the hunter/prey share ratio in basis points.
+pub fn size_ratio_bps(hunter_share: u64, prey_share: u64) -> u64 {
+    if prey_share == 0 {
+        return u64::MAX;
+    }
+    (hunter_share as u128 * fees::BASIS_POINTS_DIVISOR as u128 / prey_share as u128)
+        .min(u64::MAX as u128) as u64
+}
+
+/// Returns the index, bite fee and base escape chance of the highest size tier reached by
+/// `ratio_bps`, or `None` when the ratio is below the minimum needed to hunt.
+pub fn size_tier(ratio_bps: u64) -> Option<(u8, u64, u64)> {
+    hunting::SIZE_TIERS
+        .iter()
+        .enumerate()
+        .rev()
+        .find(|(_, (min_ratio_bps, _, _))| ratio_bps >= *min_ratio_bps)
+        .map(|(tier, (_, fee_bps, escape_bps))| (tier as u8, *fee_bps, *escape_bps))
+}
+
+/// Returns the chance, in basis points, that `prey` escapes `hunter`: the size tier's base
+/// chance, raised while the ocean is stormy and fading the longer the prey has gone
+/// unfed past hunger.
+pub fn escape_chance_bps(ocean: &Ocean, hunter: &Fish, prey: &Fish, current_time: i64) -> u64 {
+    let base_bps = match size_tier(size_ratio_bps(hunter.share, prey.share)) {
+        Some((_, _, escape_bps)) => escape_bps,
+        None => return 0,
+    };
+    let chance_bps = if ocean.is_storm {
+        base_bps + hunting::STORM_ESCAPE_BONUS_BPS
+    } else {
+        base_bps
+    };
+
+    let hungry_for = (current_time - (prey.last_fed_at + Fish::PREY_COOLDOWN)).max(0) as u64;
+    let decay_bps = (hungry_for as u128 * hunting::ESCAPE_DECAY_PER_DAY_BPS as u128
+        / ocean::DAY_DURATION as u128)
+        .min(fees::BASIS_POINTS_DIVISOR as u128) as u64;
+
+    chance_bps * (fees::BASIS_POINTS_DIVISOR - decay_bps) / fees::BASIS_POINTS_DIVISOR
+}
+
+/// Escape chance of a hunt and the roll drawn against it, both in basis points.
+pub struct EscapeRoll {
+    pub chance_bps: u64,
+    pub roll_bps: u64,
+}
+
+impl EscapeRoll {
+    /// Returns true when the prey got away.
+    pub fn escaped(&self) -> bool {
+        self.roll_bps < self.chance_bps
+    }
+}
+
+/// Fails unless the running instruction was invoked directly by the transaction and is
+/// its last instruction. Nothing can then run after the escape roll within the same
+/// transaction to inspect the outcome and revert an unlucky hunt for free.
+pub fn ensure_roll_is_final(instructions_sysvar: &AccountInfo) -> Result<()> {
+    require!(
+        get_stack_height() == TRANSACTION_LEVEL_STACK_HEIGHT,
+        ErrorCode::HuntViaCpi
+    );
+    let current = load_current_index_checked(instructions_sysvar)? as usize;
+    require!(
+        load_instruction_at_checked(current + 1, instructions_sysvar).is_err(),
+        ErrorCode::HuntNotLastInstruction
+    );
+    Ok(())
+}
+
+/// Rolls whether `prey` escapes `hunter`, drawing from the same entropy source as the
+/// daily ocean mode roll.
+///
+/// The roll is not secure randomness: its inputs (clock, slot, recent slot hash and the
+/// two fish) are public, so a caller can simulate it against the slot it expects to land
+/// in and only submit favourable hunts, and a block producer can pick the outcome.
+/// Callers must use [`ensure_roll_is_final`] so an unlucky roll cannot be reverted within
+/// its transaction; the remaining bias is accepted until a VRF or commit/reveal source
+/// replaces it.
+pub fn roll_escape(
+    ocean: &Ocean,
+    hunter: &Fish,
+    prey: &Fish,
+    current_time: i64,
+    slot_hashes: &AccountInfo,
+) -> Result<EscapeRoll> {
+    let slot = Clock::get()?.slot;
+    let mut buf = [0u8; 8 * 5];
+    buf[0..8].copy_from_slice(&(current_time as u64).to_le_bytes());
+    buf[8..16].copy_from_slice(&slot.to_le_bytes());
+    buf[16..24].copy_from_slice(&hunter.id.to_le_bytes());
+    buf[24..32].copy_from_slice(&prey.id.to_le_bytes());
+    buf[32..40].copy_from_slice(&(prey.last_fed_at as u64).to_le_bytes());
+
+    Ok(EscapeRoll {
+        chance_bps: escape_chance_bps(ocean, hunter, prey, current_time),
+        roll_bps: entropy_seed(&buf, slot_hashes) % fees::BASIS_POINTS_DIVISOR,
+    })
+}
+
+/// Evaluates every rule `hunt_fish` enforces for the pair without failing early, so
+/// callers can see all blocking reasons and when the time-based ones will clear.
+pub fn evaluate_hunt(
+    hunter: &Fish,
+    prey: &Fish,
+    expected_prey_share: u64,
+    slippage_bps: u64,
+    current_time: i64,
+) -> HuntCheck {
+    let mut check = HuntCheck {
+        prey_share: prey.share,
+        ..Default::default()
+    };
+
+    if hunter.share == 0 {
+        check.failing |= hunting::CHECK_HUNTER_DEAD;
+    }
+    if prey.share == 0 {
+        check.failing |= hunting::CHECK_PREY_DEAD;
+    }
+    if hunter.trade_lock != Fish::TRADE_LOCK_NONE {
+        check.failing |= hunting::CHECK_HUNTER_TRADE_LOCKED;
+    }
+    if hunter.id == prey.id {
+        check.failing |= hunting::CHECK_SAME_FISH;
+    }
+    if hunter.owner == prey.owner {
+        check.failing |= hunting::CHECK_SAME_OWNER;
+    }
+    if hunter.share <= prey.share {
+        check.failing |= hunting::CHECK_PREY_TOO_HEAVY;
+    } else if size_tier(size_ratio_bps(hunter.share, prey.share)).is_none() {
+        check.failing |= hunting::CHECK_SIZE_RATIO;
+    }
+    if current_time < hunter.can_hunt_after {
+        check.failing |= hunting::CHECK_HUNTER_ON_COOLDOWN;
+        check.hunter_ready_at = hunter.can_hunt_after;
+    }
+    if prey.is_protected && current_time < prey.protection_ends_at {
+        check.failing |= hunting::CHECK_PREY_PROTECTED;
+        check.prey_protected_until = prey.protection_ends_at;
+    }
+    let prey_hungry_at = prey.last_fed_at + Fish::PREY_COOLDOWN;
+    if current_time < prey_hungry_at {
+        check.failing |= hunting::CHECK_PREY_NOT_HUNGRY;
+        check.prey_hungry_at = prey_hungry_at;
+    }
+    if prey.marked_by_hunter_id > 0
+        && prey.marked_by_hunter_id != hunter.id
+        && current_time <= prey.mark_expires_at
+    {
+        check.failing |= hunting::CHECK_MARK_EXCLUSIVITY;
+        check.mark_exclusivity_ends_at = prey.mark_expires_at + 1;
+    }
+
+    let (lower, upper) = slippage_bounds(expected_prey_share, slippage_bps);
+    check.min_expected_share = lower;
+    check.max_expected_share = upper;
+    if prey.share < lower || prey.share > upper {
+        check.failing |= hunting::CHECK_SLIPPAGE;
+    }
+
+    check.can_hunt_now = check.failing == 0;
+    check.ready_at = check
+        .hunter_ready_at
+        .max(check.prey_protected_until)
+        .max(check.prey_hungry_at)
+        .max(check.mark_exclusivity_ends_at);
+    check
+}
+
+impl HuntCheck {
+    /// Converts the first failing rule, in the order `hunt_fish` has always checked them,
+    /// into its error code.
+    pub fn ensure_passes(&self) -> Result<()> {
+        let ordered = [
+            (hunting::CHECK_HUNTER_DEAD, ErrorCode::FishAlreadyDead),
+            (hunting::CHECK_PREY_DEAD, ErrorCode::FishAlreadyDead),
+            (
+                hunting::CHECK_HUNTER_TRADE_LOCKED,
+                ErrorCode::FishTradeLocked,
+            ),
+            (hunting::CHECK_SAME_FISH, ErrorCode::InvalidPrey),
+            (hunting::CHECK_SAME_OWNER, ErrorCode::InvalidPrey),
+            (hunting::CHECK_PREY_TOO_HEAVY, ErrorCode::PreyTooHeavy),
+            (hunting::CHECK_SIZE_RATIO, ErrorCode::SizeRatioTooLow),
+            (
+                hunting::CHECK_HUNTER_ON_COOLDOWN,
+                ErrorCode::HuntingOnCooldown,
+            ),
+            (hunting::CHECK_PREY_PROTECTED, ErrorCode::InvalidPrey),
+            (hunting::CHECK_PREY_NOT_HUNGRY, ErrorCode::InvalidPrey),
+            (
+                hunting::CHECK_MARK_EXCLUSIVITY,
+                ErrorCode::MarkExclusivityActive,
+            ),
+            (hunting::CHECK_SLIPPAGE, ErrorCode::SlippageExceeded),
+        ];
+        for (bit, error) in ordered {
+            if self.failing & bit != 0 {
+                return Err(error.into());
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ocean(is_storm: bool) -> Ocean {
+        Ocean {
+            admin: Pubkey::default(),
+            total_fish_count: 0,
+            total_shares: 0,
+            balance_fishes: 0,
+            vault_bump: 0,
+            last_feeding_update: 0,
+            next_fish_id: 0,
+            vault: Pubkey::default(),
+            is_storm,
+            feeding_percentage: 0,
+            storm_probability_bps: 0,
+            last_cycle_mode: 0,
+            cycle_start_time: 0,
+            next_mode_change_time: 0,
+        }
+    }
+
+    fn fish(share: u64) -> Fish {
+        Fish {
+            share,
+            ..Fish::default()
+        }
+    }
+
+    #[test]
+    fn size_tier_picks_the_highest_tier_reached() {
+        assert_eq!(size_tier(hunting::MIN_SIZE_RATIO_BPS - 1), None);
+        assert_eq!(
+            size_tier(hunting::MIN_SIZE_RATIO_BPS),
+            Some((0, 3_000, 2_500))
+        );
+        assert_eq!(size_tier(29_999), Some((1, 2_000, 1_500)));
+        assert_eq!(size_tier(30_000), Some((2, 1_500, 800)));
+        assert_eq!(size_tier(u64::MAX), Some((3, 1_000, 300)));
+    }
+
+    #[test]
+    fn escape_chance_uses_tier_and_storm_bonus() {
+        let hunter = fish(2_000);
+        let prey = fish(1_000);
+        let hungry_at = prey.last_fed_at + Fish::PREY_COOLDOWN;
+
+        assert_eq!(
+            escape_chance_bps(&ocean(false), &hunter, &prey, hungry_at),
+            1_500
+        );
+        assert_eq!(
+            escape_chance_bps(&ocean(true), &hunter, &prey, hungry_at),
+            1_500 + hunting::STORM_ESCAPE_BONUS_BPS
+        );
+        assert_eq!(
+            escape_chance_bps(&ocean(false), &fish(1_000), &prey, hungry_at),
+            0
+        );
+    }
+
+    #[test]
+    fn escape_chance_fades_while_prey_stays_hungry() {
+        let hunter = fish(2_000);
+        let prey = fish(1_000);
+        let hungry_at = prey.last_fed_at + Fish::PREY_COOLDOWN;
+        let calm = ocean(false);
+
+        assert_eq!(
+            escape_chance_bps(&calm, &hunter, &prey, hungry_at + ocean::DAY_DURATION),
+            1_125
+        );
+        assert_eq!(
+            escape_chance_bps(&calm, &hunter, &prey, hungry_at + 10 * ocean::DAY_DURATION),
+            0
+        );
+    }
+}
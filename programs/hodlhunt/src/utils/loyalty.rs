@@ -0,0 +1,105 @@
+use crate::constants::{loyalty, streaks};
+use crate::state::Fish;
+
+/// Returns the discount of the highest tier in `tiers` whose threshold `value` reaches.
+fn tier_discount<T: PartialOrd + Copy>(tiers: &[(T, u64)], value: T) -> u64 {
+    tiers
+        .iter()
+        .rev()
+        .find(|(threshold, _)| value >= *threshold)
+        .map(|(_, discount_bps)| *discount_bps)
+        .unwrap_or(0)
+}
+
+/// Returns the loyalty discount on feed commission and exit fees earned by `fish` through
+/// its age, feeding streak and hunts, capped at `MAX_DISCOUNT_BPS`.
+pub fn loyalty_discount_bps(fish: &Fish, current_time: i64) -> u64 {
+    let age = current_time.saturating_sub(fish.created_at);
+    let discount = tier_discount(&loyalty::AGE_TIERS, age)
+        + tier_discount(&loyalty::STREAK_TIERS, fish.feeding_streak)
+        + tier_discount(&loyalty::HUNT_TIERS, fish.total_hunts);
+    discount.min(loyalty::MAX_DISCOUNT_BPS)
+}
+
+/// Returns the pool-fee share, in basis points, awarded when a streak reaches `streak`,
+/// or zero when it is not a milestone.
+pub fn streak_milestone_bonus_bps(streak: u32) -> u64 {
+    streaks::MILESTONES
+        .iter()
+        .find(|(milestone, _)| *milestone == streak)
+        .map(|(_, bonus_bps)| *bonus_bps)
+        .unwrap_or(0)
+}
+
+/// Returns the next streak milestone above `streak`, or zero when all are reached.
+pub fn next_streak_milestone(streak: u32) -> u32 {
+    streaks::MILESTONES
+        .iter()
+        .map(|(milestone, _)| *milestone)
+        .find(|milestone| *milestone > streak)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::ocean::DAY_DURATION;
+
+    const NOW: i64 = 1_000 * DAY_DURATION;
+
+    fn fish(age: i64, feeding_streak: u32, total_hunts: u64) -> Fish {
+        Fish {
+            created_at: NOW - age,
+            feeding_streak,
+            total_hunts,
+            ..Fish::default()
+        }
+    }
+
+    #[test]
+    fn discount_starts_at_each_tier_boundary() {
+        assert_eq!(loyalty_discount_bps(&fish(0, 0, 0), NOW), 0);
+        assert_eq!(
+            loyalty_discount_bps(&fish(30 * DAY_DURATION - 1, 2, 4), NOW),
+            0
+        );
+        assert_eq!(
+            loyalty_discount_bps(&fish(30 * DAY_DURATION, 0, 0), NOW),
+            500
+        );
+        assert_eq!(
+            loyalty_discount_bps(&fish(90 * DAY_DURATION, 0, 0), NOW),
+            1_000
+        );
+        assert_eq!(loyalty_discount_bps(&fish(0, 3, 0), NOW), 500);
+        assert_eq!(loyalty_discount_bps(&fish(0, 25, 0), NOW), 1_500);
+        assert_eq!(loyalty_discount_bps(&fish(0, 0, 20), NOW), 1_000);
+        assert_eq!(loyalty_discount_bps(&fish(0, 10, 5), NOW), 1_500);
+    }
+
+    #[test]
+    fn discount_is_capped() {
+        let veteran = fish(180 * DAY_DURATION, 25, 50);
+        assert_eq!(
+            loyalty_discount_bps(&veteran, NOW),
+            loyalty::MAX_DISCOUNT_BPS
+        );
+    }
+
+    #[test]
+    fn milestone_bonus_only_on_exact_milestones() {
+        assert_eq!(streak_milestone_bonus_bps(3), 0);
+        assert_eq!(streak_milestone_bonus_bps(4), 2_500);
+        assert_eq!(streak_milestone_bonus_bps(5), 0);
+        assert_eq!(streak_milestone_bonus_bps(26), 10_000);
+    }
+
+    #[test]
+    fn next_milestone_is_zero_after_the_last() {
+        assert_eq!(next_streak_milestone(0), 4);
+        assert_eq!(next_streak_milestone(4), 13);
+        assert_eq!(next_streak_milestone(25), 26);
+        assert_eq!(next_streak_milestone(26), 0);
+        assert_eq!(next_streak_milestone(100), 0);
+    }
+}
@@ -1,4 +1,5 @@
 use crate::constants::fees;
+use crate::constants::ocean::VALUE_PER_SHARE_PRECISION;
 use crate::{Fish, Ocean};
 use solana_safe_math::SafeMath;
 
@@ -20,6 +21,21 @@ pub fn share_to_value(ocean: &Ocean, share: u64) -> u64 {
     numerator.safe_div(denom).unwrap_or(0) as u64
 }
 
+/// Returns the lamport value of one share scaled by `VALUE_PER_SHARE_PRECISION`, so
+/// clients can price shares without replicating the rounding in `share_to_value`.
+pub fn value_per_share_scaled(ocean: &Ocean) -> u64 {
+    if ocean.total_shares == 0 {
+        return 0;
+    }
+    let numerator = (ocean.balance_fishes as u128)
+        .safe_mul(VALUE_PER_SHARE_PRECISION as u128)
+        .unwrap_or(0);
+    numerator
+        .safe_div(ocean.total_shares as u128)
+        .unwrap_or(0)
+        .min(u64::MAX as u128) as u64
+}
+
 /// Computes the number of shares that correspond to a deposited value, maintaining
 /// proportional ownership of the ocean while rounding to the nearest share.
 pub fn new_share(ocean: &Ocean, value: u64) -> u64 {
@@ -41,6 +57,14 @@ pub fn new_share(ocean: &Ocean, value: u64) -> u64 {
     }
 }
 
+/// Reduces a fee by the given discount in basis points, never below zero.
+pub fn apply_fee_discount(fee: u64, discount_bps: u64) -> u64 {
+    let discount = fee
+        .saturating_mul(discount_bps.min(fees::BASIS_POINTS_DIVISOR))
+        .saturating_div(fees::BASIS_POINTS_DIVISOR);
+    fee.saturating_sub(discount)
+}
+
 /// Returns the raw feeding requirement for the provided share amount, applying the
 /// current ocean feeding percentage and ignoring hunt refunds or minimum thresholds.
 pub fn base_feeding_requirement(ocean: &Ocean, share: u64) -> u64 {
@@ -1,7 +1,17 @@
+pub mod entropy;
+pub mod hunting;
+pub mod loyalty;
 pub mod marks;
 pub mod math;
+pub mod quotes;
 pub mod transfers;
+pub mod unlocks;
 
+pub use entropy::*;
+pub use hunting::*;
+pub use loyalty::*;
 pub use marks::*;
 pub use math::*;
+pub use quotes::*;
 pub use transfers::*;
+pub use unlocks::*;
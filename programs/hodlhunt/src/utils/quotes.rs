@@ -0,0 +1,226 @@
+use crate::constants::{fees, hunting, marks, ocean as ocean_cfg, shields};
+use crate::state::{Fish, Ocean};
+use crate::utils::*;
+use crate::views::*;
+use anchor_lang::solana_program::native_token::LAMPORTS_PER_SOL;
+
+/// Splits a creation deposit into the admin and pool fees after applying a discount.
+pub fn creation_fees(deposit: u64, fee_discount_bps: u64) -> (u64, u64) {
+    let admin_fee = apply_fee_discount(deposit / fees::CREATION_FEE_DIVISOR, fee_discount_bps);
+    let pool_fee = apply_fee_discount(deposit / fees::CREATION_FEE_DIVISOR, fee_discount_bps);
+    (admin_fee, pool_fee)
+}
+
+/// Computes the full cost of feeding `fish` with `feeding_amount` and the shares it would
+/// mint, mirroring the order of operations in `feed_fish::handle`.
+pub fn feed_breakdown(
+    ocean: &Ocean,
+    fish: &Fish,
+    feeding_amount: u64,
+    current_time: i64,
+) -> FeedQuote {
+    let loyalty_discount_bps = loyalty_discount_bps(fish, current_time);
+    let commission = apply_fee_discount(
+        feeding_amount / fees::FEED_COMMISSION_DIVISOR,
+        loyalty_discount_bps,
+    );
+    let admin_fee = commission / fees::FEE_SPLIT_DIVISOR;
+    let pool_fee = commission - admin_fee;
+
+    let mut sim = ocean.clone();
+    sim.balance_fishes = sim.balance_fishes.saturating_add(feeding_amount + pool_fee);
+    let shares_minted = new_share(&sim, feeding_amount);
+    sim.total_shares = sim.total_shares.saturating_add(shares_minted);
+
+    // A streak milestone credits part of the pool fee to this fish instead of the pool.
+    let feeding_streak = fish.next_feeding_streak(current_time);
+    let streak_bonus_bps = if feeding_streak > fish.feeding_streak {
+        streak_milestone_bonus_bps(feeding_streak)
+    } else {
+        0
+    };
+    let streak_bonus_value = pool_fee
+        .saturating_mul(streak_bonus_bps)
+        .saturating_div(fees::BASIS_POINTS_DIVISOR);
+    let streak_bonus_shares = new_share(&sim, streak_bonus_value);
+    sim.total_shares = sim.total_shares.saturating_add(streak_bonus_shares);
+    let new_fish_share = fish
+        .share
+        .saturating_add(shares_minted)
+        .saturating_add(streak_bonus_shares);
+
+    FeedQuote {
+        feeding_amount,
+        min_required: min_feeding_amount(ocean, fish),
+        loyalty_discount_bps,
+        commission,
+        admin_fee,
+        pool_fee,
+        total_cost: feeding_amount + commission,
+        shares_minted,
+        feeding_streak,
+        streak_milestone: streak_bonus_bps > 0,
+        streak_bonus_value,
+        streak_bonus_shares,
+        new_share: new_fish_share,
+        new_value: share_to_value(&sim, new_fish_share),
+    }
+}
+
+/// Computes the fees and shares of creating a fish with `deposit`, mirroring
+/// `apply_creation_finance` followed by `mint_fish_share`.
+pub fn create_breakdown(ocean: &Ocean, deposit: u64, fee_discount_bps: u64) -> CreateQuote {
+    let (admin_fee, pool_fee) = creation_fees(deposit, fee_discount_bps);
+
+    let mut sim = ocean.clone();
+    sim.balance_fishes = sim.balance_fishes.saturating_add(pool_fee);
+    sim.balance_fishes = sim.balance_fishes.saturating_add(deposit);
+    let shares_minted = new_share(&sim, deposit);
+    sim.total_shares = sim.total_shares.saturating_add(shares_minted);
+
+    CreateQuote {
+        deposit,
+        fee_discount_bps,
+        admin_fee,
+        pool_fee,
+        total_cost: deposit + admin_fee + pool_fee,
+        shares_minted,
+        resulting_value: share_to_value(&sim, shares_minted),
+    }
+}
+
+/// Computes the exit payout of `fish` and the fees withheld from it.
+pub fn exit_breakdown(ocean: &Ocean, fish: &Fish, current_time: i64) -> ExitQuote {
+    let total_value = share_to_value(ocean, fish.share);
+    let loyalty_discount_bps = loyalty_discount_bps(fish, current_time);
+    let fee_component = apply_fee_discount(
+        total_value
+            .saturating_mul(fees::EXIT_FEE_BPS)
+            .saturating_div(fees::BASIS_POINTS_DIVISOR),
+        loyalty_discount_bps,
+    );
+    let to_player = total_value
+        .saturating_sub(fee_component)
+        .saturating_sub(fee_component);
+
+    ExitQuote {
+        total_value,
+        loyalty_discount_bps,
+        admin_fee: fee_component,
+        pool_fee: fee_component,
+        to_player,
+        shares_burned: fish.share,
+        exit_allowed: !ocean.is_storm && fish.share > 0,
+    }
+}
+
+/// Computes the cost of marking `prey` at `current_time`, including whether the prey is
+/// inside the placement window before hunger.
+pub fn mark_breakdown(ocean: &Ocean, prey: &Fish, current_time: i64) -> MarkQuote {
+    let time_until_hungry = (prey.last_fed_at + Fish::PREY_COOLDOWN) - current_time;
+    let prey_value = share_to_value(ocean, prey.share);
+    let cost_percent = if time_until_hungry <= marks::HIGH_RATE_THRESHOLD_SECONDS {
+        100
+    } else {
+        50
+    };
+
+    let raw_cost = prey_value.saturating_mul(cost_percent).saturating_div(1000);
+    let min_mark_cost = LAMPORTS_PER_SOL / 100; // 0.01 SOL
+    let cost = raw_cost.max(min_mark_cost);
+    let pool_fee = cost / 2;
+
+    MarkQuote {
+        prey_value,
+        time_until_hungry,
+        in_placement_window: time_until_hungry <= marks::PLACEMENT_WINDOW_SECONDS
+            && time_until_hungry > 0,
+        cost_percent,
+        cost,
+        admin_fee: cost - pool_fee,
+        pool_fee,
+        expires_at: prey.last_fed_at + Fish::PREY_COOLDOWN + Fish::MARK_EXCLUSIVITY_PERIOD,
+    }
+}
+
+/// Prices a shield of `duration` seconds as a share of the fish value that grows with the
+/// duration and doubles while the ocean is stormy, mirroring `buy_shield::handle`.
+pub fn shield_breakdown(
+    ocean: &Ocean,
+    fish: &Fish,
+    duration: i64,
+    current_time: i64,
+) -> ShieldQuote {
+    let fish_value = share_to_value(ocean, fish.share);
+    let daily_bps = if ocean.is_storm {
+        shields::DAILY_PRICE_BPS * shields::STORM_PRICE_MULTIPLIER_BPS / fees::BASIS_POINTS_DIVISOR
+    } else {
+        shields::DAILY_PRICE_BPS
+    };
+    let duration_secs = duration.max(0) as u128;
+    let day = ocean_cfg::DAY_DURATION as u128;
+
+    let cost = (fish_value as u128 * daily_bps as u128 * duration_secs
+        / (day * fees::BASIS_POINTS_DIVISOR as u128)) as u64;
+    let admin_fee = cost / fees::FEE_SPLIT_DIVISOR;
+
+    ShieldQuote {
+        duration,
+        fish_value,
+        cost_bps: (daily_bps as u128 * duration_secs / day) as u64,
+        cost,
+        admin_fee,
+        pool_fee: cost - admin_fee,
+        protection_ends_at: current_time + duration,
+        available_at: fish.shield_available_at(),
+        season_seconds_left: shields::MAX_SEASON_SHIELD_SECONDS
+            - fish.shield_seconds_in_season(current_time),
+    }
+}
+
+/// Computes how a hunt of `prey` by `hunter` distributes the prey share, mirroring the
+/// ocean updates performed by `hunt_fish::handle`.
+pub fn hunt_breakdown(ocean: &Ocean, hunter: &Fish, prey: &Fish, current_time: i64) -> HuntQuote {
+    let bite_share = prey.share;
+    let ratio_bps = size_ratio_bps(hunter.share, prey.share);
+    let (size_tier, fee_bps, _) = size_tier(ratio_bps).unwrap_or((0, hunting::SIZE_TIERS[0].1, 0));
+
+    let bps = fees::BASIS_POINTS_DIVISOR;
+    let to_hunter = bite_share.saturating_mul(bps - fee_bps).saturating_div(bps);
+    let to_pool = bite_share.saturating_mul(fee_bps / 2).saturating_div(bps);
+    let to_admin = bite_share.saturating_mul(fee_bps / 2).saturating_div(bps);
+
+    let to_pool_value = share_to_value(ocean, to_pool);
+    let to_admin_value = share_to_value(ocean, to_admin);
+
+    let mut sim = ocean.clone();
+    sim.total_shares = sim.total_shares.saturating_sub(to_admin + to_pool);
+    sim.balance_fishes = sim.balance_fishes.saturating_sub(to_admin_value);
+    let hunter_new_share = hunter.share.saturating_add(to_hunter);
+
+    let min_feeding_value =
+        base_feeding_requirement(&sim, hunter_new_share).max(fees::MIN_FEED_LAMPORTS);
+    let received_from_hunt_value = share_to_value(&sim, to_hunter);
+
+    HuntQuote {
+        size_tier,
+        size_ratio_bps: ratio_bps,
+        fee_bps,
+        escape_chance_bps: escape_chance_bps(ocean, hunter, prey, current_time),
+        failed_hunt_fee_share: hunter
+            .share
+            .saturating_mul(hunting::FAILED_HUNT_FEE_BPS)
+            .saturating_div(fees::BASIS_POINTS_DIVISOR),
+        bite_share,
+        to_hunter,
+        to_pool,
+        to_admin,
+        to_pool_value,
+        to_admin_value,
+        hunter_new_share,
+        hunter_new_value: share_to_value(&sim, hunter_new_share),
+        received_from_hunt_value,
+        min_feeding_value,
+        counts_as_feeding: received_from_hunt_value >= min_feeding_value,
+    }
+}
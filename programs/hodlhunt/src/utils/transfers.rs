@@ -27,3 +27,62 @@ pub fn transfer_to_admin<'a>(
     msg!("Transferred {} lamports to admin via CPI", amount);
     Ok(())
 }
+
+/// Closes a program-owned account by draining its lamports into `destination`, handing
+/// it back to the system program and truncating its data.
+pub fn close_program_account<'a>(
+    account: &AccountInfo<'a>,
+    destination: &AccountInfo<'a>,
+) -> Result<()> {
+    let lamports = account.lamports();
+    **destination.try_borrow_mut_lamports()? = destination
+        .lamports()
+        .checked_add(lamports)
+        .ok_or(ErrorCode::MathOverflow)?;
+    **account.try_borrow_mut_lamports()? = 0;
+
+    account.assign(&anchor_lang::solana_program::system_program::ID);
+    account.realloc(0, false)?;
+    Ok(())
+}
+
+/// Transfers lamports from a signing wallet through the system program, skipping the
+/// CPI entirely when the amount is zero.
+pub fn transfer_from_signer<'a>(
+    payer: &AccountInfo<'a>,
+    recipient: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    let ix = anchor_lang::solana_program::system_instruction::transfer(
+        payer.key,
+        recipient.key,
+        amount,
+    );
+    anchor_lang::solana_program::program::invoke(
+        &ix,
+        &[payer.clone(), recipient.clone(), system_program.clone()],
+    )?;
+    Ok(())
+}
+
+/// Moves lamports out of a program-owned account such as an escrow PDA.
+pub fn transfer_lamports<'a>(
+    from: &AccountInfo<'a>,
+    to: &AccountInfo<'a>,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    require!(from.lamports() >= amount, ErrorCode::InsufficientFunds);
+
+    **from.try_borrow_mut_lamports()? -= amount;
+    **to.try_borrow_mut_lamports()? += amount;
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use crate::constants::achievements;
+use crate::state::{Achievements, Fish, Ocean};
+use crate::utils::share_to_value;
+
+/// Returns the achievements whose conditions `fish` currently meets, as a bitset.
+/// Achievements tied to a specific action, such as resurrection, are granted by its handler.
+pub fn earned_achievements(ocean: &Ocean, fish: &Fish, current_time: i64) -> u16 {
+    let mut earned = 0u16;
+    let mut earn = |achievement: u8, condition: bool| {
+        if condition {
+            earned |= 1 << achievement;
+        }
+    };
+
+    earn(Achievements::FIRST_HUNT, fish.total_hunts >= 1);
+    earn(
+        Achievements::TEN_HUNTS,
+        fish.total_hunts >= achievements::HUNT_MILESTONE,
+    );
+    // Alive through a storm that began after the fish was created.
+    earn(
+        Achievements::STORM_SURVIVOR,
+        ocean.is_storm
+            && fish.share > 0
+            && fish.created_at < ocean.cycle_start_time
+            && current_time >= ocean.cycle_start_time,
+    );
+    earn(
+        Achievements::TEN_SOL_VALUE,
+        share_to_value(ocean, fish.share) >= achievements::VALUE_MILESTONE_LAMPORTS,
+    );
+    earned
+}
@@ -0,0 +1,335 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::fees;
+use crate::state::{Fish, Leaderboard, LeaderboardEntry, Ocean};
+use crate::utils::*;
+
+/// Point-in-time view of a fish returned through return data, including values derived
+/// from the ocean state so clients do not have to replicate on-chain math.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct FishSnapshot {
+    pub id: u64,
+    pub owner: Pubkey,
+    pub name: String,
+    pub share: u64,
+    pub value: u64,
+    pub is_alive: bool,
+    pub created_at: i64,
+    pub last_fed_at: i64,
+    pub hungry_at: i64,
+    pub time_until_hungry: i64,
+    pub starves_at: i64,
+    pub min_feeding_amount: u64,
+    pub can_hunt: bool,
+    pub can_hunt_after: i64,
+    pub can_be_hunted: bool,
+    pub is_protected: bool,
+    pub protection_ends_at: i64,
+    pub total_hunts: u64,
+    pub total_hunt_income: u64,
+    pub received_from_hunt_value: u64,
+    pub hunting_marks_placed: u8,
+    pub is_marked: bool,
+    pub marked_by_hunter_id: u64,
+    pub mark_expires_at: i64,
+    pub mark_cost: u64,
+    pub trade_lock: u8,
+    pub parent_fish_id: u64,
+    pub generation: u32,
+    pub resurrected_into_id: u64,
+    pub shield_available_at: i64,
+    pub shield_seconds_used: i64,
+    pub feeding_streak: u32,
+    pub best_feeding_streak: u32,
+    pub streak_window_opens_at: i64,
+    pub next_streak_milestone: u32,
+    pub loyalty_discount_bps: u64,
+    pub season_id: u64,
+    pub season_score: u64,
+    pub season_hunts: u32,
+    pub season_hunt_income: u64,
+}
+
+impl FishSnapshot {
+    /// Builds the snapshot of `fish` as seen at `current_time`.
+    pub fn build(ocean: &Ocean, fish: &Fish, current_time: i64) -> Self {
+        let hungry_at = fish.last_fed_at + Fish::PREY_COOLDOWN;
+        let is_marked = fish.marked_by_hunter_id > 0 && !fish.is_mark_expired(current_time);
+        Self {
+            id: fish.id,
+            owner: fish.owner,
+            name: fish.name.clone(),
+            share: fish.share,
+            value: share_to_value(ocean, fish.share),
+            is_alive: fish.share > 0,
+            created_at: fish.created_at,
+            last_fed_at: fish.last_fed_at,
+            hungry_at,
+            time_until_hungry: (hungry_at - current_time).max(0),
+            starves_at: fish.starves_at(),
+            min_feeding_amount: min_feeding_amount(ocean, fish),
+            can_hunt: fish.can_hunt(current_time),
+            can_hunt_after: fish.can_hunt_after,
+            can_be_hunted: fish.is_valid_prey(current_time),
+            is_protected: fish.is_protected && current_time < fish.protection_ends_at,
+            protection_ends_at: fish.protection_ends_at,
+            total_hunts: fish.total_hunts,
+            total_hunt_income: fish.total_hunt_income,
+            received_from_hunt_value: fish.received_from_hunt_value,
+            hunting_marks_placed: fish.hunting_marks_placed,
+            is_marked,
+            marked_by_hunter_id: if is_marked {
+                fish.marked_by_hunter_id
+            } else {
+                0
+            },
+            mark_expires_at: if is_marked { fish.mark_expires_at } else { 0 },
+            mark_cost: if is_marked { fish.mark_cost } else { 0 },
+            trade_lock: fish.trade_lock,
+            parent_fish_id: fish.parent_fish_id,
+            generation: fish.generation,
+            resurrected_into_id: fish.resurrected_into_id,
+            shield_available_at: fish.shield_available_at(),
+            shield_seconds_used: fish.shield_seconds_in_season(current_time),
+            feeding_streak: fish.feeding_streak,
+            best_feeding_streak: fish.best_feeding_streak,
+            streak_window_opens_at: fish.streak_window_opens_at(),
+            next_streak_milestone: next_streak_milestone(fish.feeding_streak),
+            loyalty_discount_bps: loyalty_discount_bps(fish, current_time),
+            season_id: fish.season_id,
+            season_score: fish.season_score(),
+            season_hunts: fish.season_hunts,
+            season_hunt_income: fish.season_hunt_income,
+        }
+    }
+}
+
+/// Point-in-time view of the ocean returned through return data, including the fee
+/// parameters the program charges expressed in basis points.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct OceanSnapshot {
+    pub admin: Pubkey,
+    pub vault: Pubkey,
+    pub total_fish_count: u64,
+    pub next_fish_id: u64,
+    pub total_shares: u64,
+    pub balance_fishes: u64,
+    pub value_per_share_scaled: u64,
+    pub is_storm: bool,
+    pub feeding_percentage: u16,
+    pub storm_probability_bps: u16,
+    pub cycle_start_time: i64,
+    pub next_mode_change_time: i64,
+    pub time_until_mode_change: i64,
+    pub min_deposit_lamports: u64,
+    pub min_feed_lamports: u64,
+    pub creation_fee_bps: u64,
+    pub feed_commission_bps: u64,
+    pub exit_fee_bps: u64,
+    pub transfer_fee_bps: u64,
+    pub market_royalty_bps: u64,
+    pub merge_fee_bps: u64,
+    pub reaper_bounty_bps: u64,
+}
+
+impl OceanSnapshot {
+    /// Builds the snapshot of `ocean` as seen at `current_time`.
+    pub fn build(ocean: &Ocean, current_time: i64) -> Self {
+        Self {
+            admin: ocean.admin,
+            vault: ocean.vault,
+            total_fish_count: ocean.total_fish_count,
+            next_fish_id: ocean.next_fish_id,
+            total_shares: ocean.total_shares,
+            balance_fishes: ocean.balance_fishes,
+            value_per_share_scaled: value_per_share_scaled(ocean),
+            is_storm: ocean.is_storm,
+            feeding_percentage: ocean.feeding_percentage,
+            storm_probability_bps: ocean.storm_probability_bps,
+            cycle_start_time: ocean.cycle_start_time,
+            next_mode_change_time: ocean.next_mode_change_time,
+            time_until_mode_change: (ocean.next_mode_change_time - current_time).max(0),
+            min_deposit_lamports: fees::MIN_DEPOSIT_LAMPORTS,
+            min_feed_lamports: fees::MIN_FEED_LAMPORTS,
+            creation_fee_bps: fees::BASIS_POINTS_DIVISOR / fees::CREATION_FEE_DIVISOR,
+            feed_commission_bps: fees::BASIS_POINTS_DIVISOR / fees::FEED_COMMISSION_DIVISOR,
+            exit_fee_bps: fees::EXIT_FEE_BPS,
+            transfer_fee_bps: fees::TRANSFER_FEE_BPS,
+            market_royalty_bps: fees::MARKET_ROYALTY_BPS,
+            merge_fee_bps: fees::MERGE_FEE_BPS,
+            reaper_bounty_bps: fees::REAPER_BOUNTY_BPS,
+        }
+    }
+}
+
+/// A leaderboard row with the fish's current value.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct LeaderboardRow {
+    pub rank: u8,
+    pub fish_id: u64,
+    pub owner: Pubkey,
+    pub share: u64,
+    pub value: u64,
+    pub total_hunt_income: u64,
+}
+
+/// Both leaderboards of the ocean, best first.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct LeaderboardSnapshot {
+    pub by_share: Vec<LeaderboardRow>,
+    pub by_hunt_income: Vec<LeaderboardRow>,
+    pub updated_at: i64,
+}
+
+impl LeaderboardSnapshot {
+    /// Builds the snapshot of `leaderboard`, valuing shares at the current ocean state.
+    pub fn build(ocean: &Ocean, leaderboard: &Leaderboard) -> Self {
+        let rows = |entries: &[LeaderboardEntry]| {
+            entries
+                .iter()
+                .enumerate()
+                .map(|(index, entry)| LeaderboardRow {
+                    rank: index as u8 + 1,
+                    fish_id: entry.fish_id,
+                    owner: entry.owner,
+                    share: entry.share,
+                    value: share_to_value(ocean, entry.share),
+                    total_hunt_income: entry.total_hunt_income,
+                })
+                .collect()
+        };
+        Self {
+            by_share: rows(&leaderboard.by_share[..leaderboard.by_share_count as usize]),
+            by_hunt_income: rows(
+                &leaderboard.by_hunt_income[..leaderboard.by_hunt_income_count as usize],
+            ),
+            updated_at: leaderboard.updated_at,
+        }
+    }
+}
+
+/// Cost breakdown of feeding a fish with a given amount.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct FeedQuote {
+    pub feeding_amount: u64,
+    pub min_required: u64,
+    pub loyalty_discount_bps: u64,
+    pub commission: u64,
+    pub admin_fee: u64,
+    pub pool_fee: u64,
+    pub total_cost: u64,
+    pub shares_minted: u64,
+    pub feeding_streak: u32,
+    pub streak_milestone: bool,
+    pub streak_bonus_value: u64,
+    pub streak_bonus_shares: u64,
+    pub new_share: u64,
+    pub new_value: u64,
+}
+
+/// Outcome of one fish in a `feed_many` batch; `error_code` is zero when it was fed.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct FeedManyItem {
+    pub fish_id: u64,
+    pub feeding_amount: u64,
+    pub shares_minted: u64,
+    pub error_code: u32,
+}
+
+/// Per-fish results of a `feed_many` batch, in the order the fish were passed.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct FeedManyResult {
+    pub fed: u32,
+    pub skipped: u32,
+    pub total_cost: u64,
+    pub items: Vec<FeedManyItem>,
+}
+
+/// Cost breakdown of creating (or resurrecting) a fish with a given deposit.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct CreateQuote {
+    pub deposit: u64,
+    pub fee_discount_bps: u64,
+    pub admin_fee: u64,
+    pub pool_fee: u64,
+    pub total_cost: u64,
+    pub shares_minted: u64,
+    pub resulting_value: u64,
+}
+
+/// Payout breakdown of exiting the game with a fish.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct ExitQuote {
+    pub total_value: u64,
+    pub loyalty_discount_bps: u64,
+    pub admin_fee: u64,
+    pub pool_fee: u64,
+    pub to_player: u64,
+    pub shares_burned: u64,
+    pub exit_allowed: bool,
+}
+
+/// Cost breakdown of placing a hunting mark on a prey fish.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct MarkQuote {
+    pub prey_value: u64,
+    pub time_until_hungry: i64,
+    pub in_placement_window: bool,
+    pub cost_percent: u64,
+    pub cost: u64,
+    pub admin_fee: u64,
+    pub pool_fee: u64,
+    pub expires_at: i64,
+}
+
+/// Price of a shield of a given duration and when the fish may buy one.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct ShieldQuote {
+    pub duration: i64,
+    pub fish_value: u64,
+    pub cost_bps: u64,
+    pub cost: u64,
+    pub admin_fee: u64,
+    pub pool_fee: u64,
+    pub protection_ends_at: i64,
+    pub available_at: i64,
+    pub season_seconds_left: i64,
+}
+
+/// Share and value distribution of a successful hunt.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct HuntQuote {
+    pub size_tier: u8,
+    pub size_ratio_bps: u64,
+    pub fee_bps: u64,
+    pub escape_chance_bps: u64,
+    pub failed_hunt_fee_share: u64,
+    pub bite_share: u64,
+    pub to_hunter: u64,
+    pub to_pool: u64,
+    pub to_admin: u64,
+    pub to_pool_value: u64,
+    pub to_admin_value: u64,
+    pub hunter_new_share: u64,
+    pub hunter_new_value: u64,
+    pub received_from_hunt_value: u64,
+    pub min_feeding_value: u64,
+    pub counts_as_feeding: bool,
+}
+
+/// Result of evaluating every hunting rule for a hunter/prey pair. `failing` is a bitmask
+/// of `constants::hunting::CHECK_*` reasons; each `*_at` field is the time at which the
+/// matching time-based rule stops failing, or zero when it already passes.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct HuntCheck {
+    pub failing: u32,
+    pub can_hunt_now: bool,
+    pub hunter_ready_at: i64,
+    pub prey_protected_until: i64,
+    pub prey_hungry_at: i64,
+    pub mark_exclusivity_ends_at: i64,
+    pub ready_at: i64,
+    pub prey_share: u64,
+    pub min_expected_share: u64,
+    pub max_expected_share: u64,
+}